@@ -0,0 +1,95 @@
+use crate::model::cursor_model::MaskMode;
+use crate::model::report_model::ReportFormat;
+use crate::utils::editor_util::get_editor_profile;
+use crate::utils::i18n_util::{t, t_args};
+use crate::utils::report_util::{build_report, export_report, render};
+use std::path::PathBuf;
+
+// 命令行参数解析结果
+struct ReportArgs {
+    format: ReportFormat,
+    output: Option<PathBuf>,
+    editor: Option<String>,
+    mask: MaskMode,
+}
+
+fn parse_mask(value: &str) -> Option<MaskMode> {
+    match value {
+        "full" => Some(MaskMode::Full),
+        "partial" => Some(MaskMode::Partial),
+        "hashed" => Some(MaskMode::Hashed),
+        _ => None,
+    }
+}
+
+fn parse_report_args(args: &[String]) -> Result<ReportArgs, String> {
+    let mut parsed = ReportArgs {
+        format: ReportFormat::default(),
+        output: None,
+        editor: None,
+        mask: MaskMode::default(),
+    };
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let value = iter
+            .next()
+            .ok_or_else(|| t_args("cli.missing_value", &[arg]))?;
+        match arg.as_str() {
+            "--format" | "-f" => {
+                parsed.format = ReportFormat::parse(value)
+                    .ok_or_else(|| t_args("cli.invalid_value", &[arg, value]))?;
+            }
+            "--output" | "-o" => parsed.output = Some(PathBuf::from(value)),
+            "--editor" | "-e" => parsed.editor = Some(value.clone()),
+            "--mask" => {
+                parsed.mask =
+                    parse_mask(value).ok_or_else(|| t_args("cli.invalid_value", &[arg, value]))?;
+            }
+            _ => return Err(t_args("cli.unknown_arg", &[arg])),
+        }
+    }
+    Ok(parsed)
+}
+
+fn run_report(args: &[String]) -> Result<(), String> {
+    let args = parse_report_args(args)?;
+    let profile = get_editor_profile(args.editor.as_deref()).ok_or_else(|| {
+        t_args(
+            "error.unknown_editor",
+            &[args.editor.as_deref().unwrap_or_default()],
+        )
+    })?;
+    match args.output {
+        // 指定了输出文件时写入文件，否则输出到标准输出，方便管道处理
+        Some(output) => {
+            let path = export_report(&profile, args.format, args.mask, &output)?;
+            println!("{}", path);
+        }
+        None => print!(
+            "{}",
+            render(&build_report(&profile, args.mask), args.format)?
+        ),
+    }
+    Ok(())
+}
+
+// 处理命令行子命令，返回进程退出码；不是子命令时返回 None，按图形界面启动
+pub fn run_cli(args: &[String]) -> Option<i32> {
+    let (command, rest) = args.split_first()?;
+    let result = match command.as_str() {
+        "report" => run_report(rest),
+        "help" | "--help" | "-h" => {
+            println!("{}", t("cli.usage"));
+            Ok(())
+        }
+        _ => return None,
+    };
+    match result {
+        Ok(()) => Some(0),
+        Err(e) => {
+            eprintln!("{}", e);
+            eprintln!("{}", t("cli.usage"));
+            Some(2)
+        }
+    }
+}
@@ -2,22 +2,63 @@
 pub mod model;
 pub mod utils;
 
-use model::cursor_model::CursorInstallInfo;
-use std::process::Command;
-
-#[cfg(target_os = "windows")]
-use std::os::windows::process::CommandExt;
-#[cfg(target_os = "windows")]
-use winreg::enums::*;
-#[cfg(target_os = "windows")]
-use winreg::RegKey;
-
-#[tauri::command]
-async fn get_device_info() -> model::cursor_model::CursorDeviceInfo {
-    let package_path = utils::cursor_util::get_package_path();
-    if !package_path.is_empty() {
-        // 找到了包路径，读取设备信息
-        return utils::cursor_util::read_device_info(package_path);
+mod cli;
+mod tray;
+
+pub use cli::run_cli;
+
+use model::config_model::AppConfig;
+use model::crash_model::CrashSummary;
+use model::cursor_model::{CursorInstallInfo, MaskMode};
+use model::disk_model::{CacheCategory, CleanResult, DiskUsage};
+use model::doctor_model::DoctorCheck;
+use model::editor_log_model::{LogFilter, LogLine, LogSession};
+use model::editor_model::EditorProfile;
+use model::extension_model::{ExtensionDiff, ExtensionInfo};
+use model::history_model::HistoryEntry;
+use model::hook_model::HookEvent;
+use model::hot_exit_model::HotExitWorkspace;
+use model::launch_model::LaunchOptions;
+use model::maintenance_model::MaintenanceReport;
+use model::profile_model::IsolatedProfile;
+use model::recent_model::{RecentEntry, RecentUpdateResult};
+use model::report_model::ReportFormat;
+use model::settings_backup_model::{SettingsItem, SettingsRestoreResult, SettingsSnapshot};
+use model::state_model::{StateItem, StateValue};
+use model::storage_model::{StorageEntry, StorageMatch};
+use model::telemetry_model::{PrivacyOverview, TelemetrySettings};
+use model::workspace_model::{WorkspacePruneResult, WorkspaceStorageEntry};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use tauri::{Emitter, Manager};
+use tauri_plugin_autostart::{MacosLauncher, ManagerExt};
+use utils::i18n_util::{t, t_args};
+
+// 按 id 查找编辑器描述，id 为空时使用 Cursor
+fn editor_profile(editor: Option<&str>) -> Result<EditorProfile, String> {
+    utils::editor_util::get_editor_profile(editor)
+        .ok_or_else(|| t_args("error.unknown_editor", &[editor.unwrap_or_default()]))
+}
+
+// 支持的编辑器列表（内置清单 + 用户清单）
+#[tauri::command]
+async fn list_editor_profiles() -> Vec<EditorProfile> {
+    utils::editor_util::load_editor_profiles()
+}
+
+// 获取机器码，默认部分遮盖，可通过 mask 指定展示方式
+#[tauri::command]
+async fn get_device_info(
+    editor: Option<String>,
+    mask: Option<MaskMode>,
+) -> model::cursor_model::CursorDeviceInfo {
+    if let Ok(profile) = editor_profile(editor.as_deref()) {
+        let package_path = utils::cursor_util::get_package_path(&profile);
+        if !package_path.is_empty() {
+            // 找到了包路径，读取设备信息
+            return utils::cursor_util::read_device_info(package_path, &profile.telemetry_keys)
+                .masked(mask.unwrap_or_default());
+        }
     }
 
     // 如果没有找到有效路径，返回默认值
@@ -28,318 +69,855 @@ async fn get_device_info() -> model::cursor_model::CursorDeviceInfo {
         "".to_owned(),
     )
 }
+
+// 重置机器码，重置前先备份 storage.json，备份失败时不做任何修改
 #[tauri::command]
-async fn reset_device_info() -> model::cursor_model::CursorDeviceInfo {
-    let package_path = utils::cursor_util::get_package_path();
-    if !package_path.is_empty() {
-        return utils::cursor_util::reset_device_info(package_path);
+async fn reset_device_info(
+    editor: Option<String>,
+    mask: Option<MaskMode>,
+) -> Result<model::cursor_model::CursorDeviceInfo, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    let target = utils::cursor_util::get_package_path(&profile);
+    // 前置钩子失败时取消重置
+    if let Err(e) =
+        utils::hook_util::run_configured(HookEvent::BeforeReset, &profile, &target, None)
+    {
+        utils::history_util::record("reset", &profile.id, false, &e);
+        return Err(e);
     }
-    model::cursor_model::CursorDeviceInfo::new(
-        "".to_owned(),
-        "".to_owned(),
-        "".to_owned(),
-        "".to_owned(),
-    )
+    let (package_path, _) = match backup_storage(&profile) {
+        Ok(paths) => paths,
+        Err(e) => {
+            let _ = utils::hook_util::run_configured(
+                HookEvent::AfterReset,
+                &profile,
+                &target,
+                Some(false),
+            );
+            return Err(e);
+        }
+    };
+    let message = t_args("log.reset", &[&package_path]);
+    utils::log_util::info(&message);
+    utils::history_util::record("reset", &profile.id, true, &message);
+    let device_info = utils::cursor_util::reset_device_info(package_path.clone(), &profile);
+    let _ = utils::hook_util::run_configured(
+        HookEvent::AfterReset,
+        &profile,
+        &package_path,
+        Some(true),
+    );
+    Ok(device_info.masked(mask.unwrap_or_default()))
 }
 
-// 获取cursor安装路径 - 跨平台实现
+// 手动备份 storage.json，返回备份文件路径
 #[tauri::command]
-async fn get_cursor_install_info() -> CursorInstallInfo {
-    let mut install_info =
-        CursorInstallInfo::new("".to_owned(), "".to_owned(), "".to_owned(), "".to_owned());
-    #[cfg(target_os = "windows")]
-    {
-        let hkcu = RegKey::predef(HKEY_CURRENT_USER);
-        let reg_path = r"Software\Microsoft\Windows\CurrentVersion\Uninstall\{DADADADA-ADAD-ADAD-ADAD-ADADADADADAD}}_is1";
-        if let Ok(subkey) = hkcu.open_subkey(reg_path) {
-            if let Ok(install_path) = subkey.get_value::<String, _>("InstallLocation") {
-                install_info.install_path = install_path;
-            }
-            if let Ok(install_language) = subkey.get_value::<String, _>("Inno Setup: Language") {
-                install_info.install_language = install_language;
-            }
-            if let Ok(install_version) = subkey.get_value::<String, _>("DisplayVersion") {
-                install_info.install_version = install_version;
-            }
-            if let Ok(install_user) = subkey.get_value::<String, _>("Inno Setup: User") {
-                install_info.install_user = install_user;
-            }
+async fn backup_device_info(editor: Option<String>) -> Result<String, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    backup_storage(&profile).map(|(_, backup_path)| backup_path)
+}
+
+// 备份当前的 storage.json，返回 (storage.json 路径, 备份文件路径)
+fn backup_storage(profile: &EditorProfile) -> Result<(String, String), String> {
+    let package_path = utils::cursor_util::get_package_path(profile);
+    if package_path.is_empty() {
+        let message = t("error.storage_not_found");
+        utils::log_util::error(&message);
+        return Err(message);
+    }
+    match utils::backup_util::backup_file(&package_path, "storage") {
+        Ok(backup_path) => {
+            utils::log_util::info(&t_args("log.backup", &[&backup_path]));
+            Ok((package_path, backup_path))
+        }
+        Err(e) => {
+            let message = t_args("error.backup", &[&e]);
+            utils::log_util::error(&message);
+            Err(message)
         }
-        return install_info;
     }
+}
 
-    #[cfg(target_os = "macos")]
-    {
-        // macOS 上查找 Cursor 应用
-        let applications = vec![
-            "/Applications/Cursor.app".to_string(),
-            format!(
-                "{}/Applications/Cursor.app",
-                std::env::var("HOME").unwrap_or_default()
-            ),
-        ];
-
-        for app_path in applications {
-            if std::path::Path::new(&app_path).exists() {
-                install_info.install_path = app_path.to_string();
-                break;
-            }
-        }
-        // 如果没找到，尝试使用 mdfind 命令查找
-        if install_info.install_path.is_empty() {
-            if let Ok(output) = Command::new("mdfind")
-                .args(["kMDItemCFBundleIdentifier == 'com.cursor.Cursor'"])
-                .output()
-            {
-                if let Ok(stdout) = String::from_utf8(output.stdout) {
-                    let lines: Vec<&str> = stdout.lines().collect();
-                    if !lines.is_empty() {
-                        install_info.install_path = lines[0].to_string();
-                    }
-                }
-            }
+// 显示完整机器码，仅在用户明确要求时调用
+#[tauri::command]
+async fn reveal_device_info(editor: Option<String>) -> model::cursor_model::CursorDeviceInfo {
+    get_device_info(editor, Some(MaskMode::Full)).await
+}
+
+// 获取编辑器安装信息，返回给前端前统一脱敏
+#[tauri::command]
+async fn get_cursor_install_info(editor: Option<String>) -> CursorInstallInfo {
+    match editor_profile(editor.as_deref()) {
+        Ok(profile) => utils::redact_util::redact_install_info(
+            &utils::install_util::find_install_info(&profile),
+        ),
+        Err(_) => {
+            CursorInstallInfo::new("".to_owned(), "".to_owned(), "".to_owned(), "".to_owned())
         }
+    }
+}
 
-        // 获取版本信息
-        if !install_info.install_path.is_empty() {
-            // 从Info.plist获取版本信息
-            let info_plist_path = format!("{}/Contents/Info.plist", install_info.install_path);
-            if std::path::Path::new(&info_plist_path).exists() {
-                // 使用defaults命令读取版本信息
-                if let Ok(output) = Command::new("defaults")
-                    .args(["read", &info_plist_path, "CFBundleShortVersionString"])
-                    .output()
-                {
-                    if let Ok(version) = String::from_utf8(output.stdout) {
-                        install_info.install_version = version.trim().to_string();
-                    }
-                }
+// 重启编辑器（默认 Cursor）。强制结束进程可能丢弃热退出数据，
+// 存在未保存文件的备份时需要 force 为 true 才会继续
+#[tauri::command]
+async fn restart_cursor(editor: Option<String>, force: Option<bool>) -> Result<bool, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    let pending = utils::hot_exit_util::count_hot_exit_backups(&profile);
+    if pending > 0 && !force.unwrap_or(false) {
+        return Err(t_args("error.hot_exit_pending", &[&pending.to_string()]));
+    }
+    let install_info = utils::install_util::find_install_info(&profile);
+    let cursor_path = utils::install_util::get_executable_path(&profile, &install_info);
+    if cursor_path.is_empty() {
+        return Ok(false);
+    }
+    // 前置钩子失败时取消重启
+    utils::hook_util::run_configured(HookEvent::BeforeRestart, &profile, &cursor_path, None)?;
+    utils::log_util::info(&t_args("log.restart", &[&cursor_path]));
 
-                // 尝试多种方法获取Cursor的语言和用户信息
-                let home_dir = std::env::var("HOME").unwrap_or_default();
-                let cursor_config_dir = format!("{}/Library/Application Support/Cursor", home_dir);
-
-                // 1. 尝试从Preferences文件获取语言设置
-                let preferences_path = format!("{}/Preferences", cursor_config_dir);
-                if std::path::Path::new(&preferences_path).exists() {
-                    if let Ok(output) = Command::new("cat").arg(&preferences_path).output() {
-                        if let Ok(prefs_content) = String::from_utf8(output.stdout) {
-                            if let Some(lang_pos) = prefs_content.find("dictionaries\":") {
-                                let lang_start = lang_pos + 14; // dictionaries":[" 的长度
-                                if let Some(lang_end) = prefs_content[lang_start..].find("]") {
-                                    let language =
-                                        &prefs_content[lang_start..lang_start + lang_end];
-                                    // 处理引号和逗号
-                                    let cleaned_lang = language
-                                        .replace('"', "")
-                                        .replace('[', "")
-                                        .trim()
-                                        .to_string();
-                                    if !cleaned_lang.is_empty() {
-                                        install_info.install_language = cleaned_lang;
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
+    // 先关闭编辑器进程，然后重新启动
+    utils::process_util::kill(&profile);
+    let launched = utils::process_util::launch(&profile, &cursor_path, &[], &[]);
+    let _ = utils::hook_util::run_configured(
+        HookEvent::AfterRestart,
+        &profile,
+        &cursor_path,
+        Some(launched),
+    );
+    Ok(launched)
+}
 
-                // 2. 尝试从Git日志中获取用户名和邮箱
-                let logs_dir = format!("{}/logs", cursor_config_dir);
-                if std::path::Path::new(&logs_dir).exists() {
-                    // 使用grep命令从日志中查找用户名和邮箱
-                    if let Ok(output) = Command::new("grep")
-                        .args(["-r", "Stored git author name", &logs_dir])
-                        .output()
-                    {
-                        if let Ok(log_content) = String::from_utf8(output.stdout) {
-                            if !log_content.is_empty() {
-                                // 从日志中提取用户名和邮箱
-                                if let Some(author_pos) = log_content.find("global state: ") {
-                                    let author_start = author_pos + 14; // "global state: " 的长度
-                                    let author_info = &log_content[author_start..];
-
-                                    // 如果包含用户名和邮箱的格式如"李良安 <1120777912@qq.com>"
-                                    if let Some(email_start) = author_info.find('<') {
-                                        let username = author_info[..email_start].trim();
-                                        if let Some(email_end) =
-                                            author_info[email_start..].find('>')
-                                        {
-                                            let email = &author_info
-                                                [email_start + 1..email_start + email_end];
-                                            if !username.is_empty() && !email.is_empty() {
-                                                // 同时显示用户名和邮箱，格式为"用户名(邮箱)"
-                                                install_info.install_user =
-                                                    format!("{} ({})", username, email);
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
+// 按选项启动编辑器：打开文件夹或文件、新窗口、禁用扩展、日志级别、用户数据目录和环境变量
+#[tauri::command]
+async fn launch_cursor(editor: Option<String>, options: LaunchOptions) -> Result<bool, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    let install_info = utils::install_util::find_install_info(&profile);
+    let cursor_path = utils::install_util::get_executable_path(&profile, &install_info);
+    if cursor_path.is_empty() {
+        return Err(t_args("error.executable_not_found", &[&profile.name]));
+    }
+    utils::log_util::info(&t_args("log.launch", &[&cursor_path]));
+    let launcher = utils::launch_util::SystemLauncher { profile: &profile };
+    utils::launch_util::launch_with(&launcher, &cursor_path, &options)
+}
 
-                // 3. 如果从Git日志中没有获取到用户名，尝试从sentry/scope_v3.json获取邮箱
-                if install_info.install_user.is_empty() {
-                    let sentry_path = format!("{}/sentry/scope_v3.json", cursor_config_dir);
-                    if std::path::Path::new(&sentry_path).exists() {
-                        if let Ok(output) = Command::new("cat").arg(&sentry_path).output() {
-                            if let Ok(sentry_content) = String::from_utf8(output.stdout) {
-                                // 尝试获取用户邮箱
-                                if let Some(email_pos) = sentry_content.find("\"email\":") {
-                                    let email_start = email_pos + 9; // "email":" 的长度
-                                    if let Some(email_end) =
-                                        sentry_content[email_start..].find("\"")
-                                    {
-                                        let email =
-                                            &sentry_content[email_start..email_start + email_end];
-                                        if !email.is_empty() {
-                                            install_info.install_user = format!("用户({})", email);
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
+// 编辑器运行时会占用或重新写入数据目录中的文件，这类操作需要先关闭编辑器
+fn ensure_not_running(profile: &EditorProfile) -> Result<(), String> {
+    if utils::process_util::is_running(profile) {
+        let message = t_args("error.editor_running", &[&profile.name]);
+        utils::log_util::error(&message);
+        return Err(message);
+    }
+    Ok(())
+}
 
-                // 3. 尝试从languagepacks.json获取语言设置
-                if install_info.install_language.is_empty() {
-                    let lang_packs_path = format!("{}/languagepacks.json", cursor_config_dir);
-                    if std::path::Path::new(&lang_packs_path).exists() {
-                        if let Ok(output) = Command::new("cat").arg(&lang_packs_path).output() {
-                            if let Ok(lang_content) = String::from_utf8(output.stdout) {
-                                // 如果文件包含中文语言包
-                                if lang_content.contains("zh-cn") {
-                                    install_info.install_language = "zh-cn".to_string();
-                                } else if lang_content.contains("en") {
-                                    install_info.install_language = "en".to_string();
-                                }
-                            }
-                        }
-                    }
-                }
+// 检测编辑器（默认 Cursor）是否正在运行
+#[tauri::command]
+async fn is_cursor_running(editor: Option<String>) -> bool {
+    match editor_profile(editor.as_deref()) {
+        Ok(profile) => utils::process_util::is_running(&profile),
+        Err(_) => false,
+    }
+}
 
-                // 4. 检查中文语言包目录是否存在
-                if install_info.install_language.is_empty() {
-                    let zh_lang_dir = format!(
-                        "{}/clp/6d6cd612ec0ae3cd32737a6f6b7ad966.zh-cn",
-                        cursor_config_dir
-                    );
-                    if std::path::Path::new(&zh_lang_dir).exists() {
-                        install_info.install_language = "zh-cn".to_string();
-                    }
-                }
+// 检测 Cursor 是否正在运行，供托盘等后台任务使用
+fn check_cursor_running() -> bool {
+    editor_profile(None)
+        .map(|profile| utils::process_util::is_running(&profile))
+        .unwrap_or(false)
+}
 
-                // 5. 如果还是没有获取到用户信息，尝试使用应用程序路径的用户名部分
-                if install_info.install_user.is_empty() && !install_info.install_path.is_empty() {
-                    if install_info.install_path.contains("/Users/") {
-                        if let Some(user_start) = install_info.install_path.find("/Users/") {
-                            let user_path = &install_info.install_path[user_start + 7..];
-                            if let Some(user_end) = user_path.find('/') {
-                                let username = &user_path[..user_end];
-                                if !username.is_empty() {
-                                    install_info.install_user = username.to_string();
-                                }
-                            }
-                        }
-                    }
-                }
-            }
+// 本地安装的扩展列表
+#[tauri::command]
+async fn list_extensions(editor: Option<String>) -> Result<Vec<ExtensionInfo>, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    Ok(utils::extension_util::list_extensions(&profile))
+}
+
+// 导出扩展清单，path 为空时保存到应用数据目录，返回文件路径
+#[tauri::command]
+async fn export_extensions(editor: Option<String>, path: Option<String>) -> Result<String, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    let target = match path {
+        Some(path) if !path.is_empty() => std::path::PathBuf::from(path),
+        _ => utils::extension_util::default_manifest_path(&profile),
+    };
+    let manifest_path = utils::extension_util::export_manifest(&profile, &target)?;
+    utils::log_util::info(&t_args("log.export_extensions", &[&manifest_path]));
+    Ok(manifest_path)
+}
+
+// 对比清单与本地扩展，返回缺失、版本不一致和多出的扩展
+#[tauri::command]
+async fn compare_extensions(editor: Option<String>, path: String) -> Result<ExtensionDiff, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    let manifest = utils::extension_util::read_manifest(std::path::Path::new(&path))?;
+    Ok(utils::extension_util::compare_with_manifest(
+        &profile, &manifest,
+    ))
+}
+
+// 创建配置快照（settings.json、keybindings.json、snippets 和扩展清单）
+#[tauri::command]
+async fn create_settings_snapshot(editor: Option<String>) -> Result<SettingsSnapshot, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    let snapshot = utils::settings_backup_util::create_snapshot(&profile)?;
+    utils::log_util::info(&t_args("log.settings_snapshot", &[&snapshot.id]));
+    Ok(snapshot)
+}
+
+// 列出配置快照，最新的在前
+#[tauri::command]
+async fn list_settings_snapshots(editor: Option<String>) -> Result<Vec<SettingsSnapshot>, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    utils::settings_backup_util::list_snapshots(&profile)
+}
+
+// 将快照中选中的项恢复到当前安装
+#[tauri::command]
+async fn restore_settings_snapshot(
+    editor: Option<String>,
+    id: String,
+    items: Vec<SettingsItem>,
+) -> Result<SettingsRestoreResult, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    let result = utils::settings_backup_util::restore_snapshot(&profile, &id, &items)?;
+    utils::log_util::info(&t_args("log.settings_restore", &[&id, &result.backup_path]));
+    Ok(result)
+}
+
+// 编辑器数据目录各缓存类别的占用
+#[tauri::command]
+async fn disk_usage(editor: Option<String>) -> Result<DiskUsage, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    utils::disk_util::disk_usage(&profile)
+}
+
+// 清理选中的缓存类别，编辑器运行时拒绝执行，结果记入历史
+#[tauri::command]
+async fn clean(
+    editor: Option<String>,
+    categories: Vec<CacheCategory>,
+) -> Result<CleanResult, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    ensure_not_running(&profile)?;
+    let names: Vec<String> = categories
+        .iter()
+        .map(|category| category.relative_path().to_string())
+        .collect();
+    match utils::disk_util::clean(&profile, &categories) {
+        Ok(result) => {
+            let message = t_args("log.clean", &[&names.join(", "), &result.freed.to_string()]);
+            utils::log_util::info(&message);
+            utils::history_util::record("clean", &profile.id, result.failed.is_empty(), &message);
+            Ok(result)
+        }
+        Err(e) => {
+            utils::history_util::record("clean", &profile.id, false, &e);
+            Err(e)
         }
+    }
+}
+
+// 列出 workspaceStorage 下的工作区及其对应的文件夹，标记已不存在的工作区
+#[tauri::command]
+async fn list_workspace_storage(
+    editor: Option<String>,
+) -> Result<Vec<WorkspaceStorageEntry>, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    utils::workspace_util::list_workspace_storage(&profile)
+}
 
-        return install_info;
+// 删除选中的工作区存储目录（先备份），编辑器运行时拒绝执行
+#[tauri::command]
+async fn prune_workspace_storage(
+    editor: Option<String>,
+    ids: Vec<String>,
+) -> Result<WorkspacePruneResult, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    ensure_not_running(&profile)?;
+    match utils::workspace_util::prune_workspace_storage(&profile, &ids) {
+        Ok(result) => {
+            let message = t_args(
+                "log.prune_workspaces",
+                &[&result.removed.len().to_string(), &result.backup_path],
+            );
+            utils::log_util::info(&message);
+            utils::history_util::record("prune_workspaces", &profile.id, true, &message);
+            Ok(result)
+        }
+        Err(e) => {
+            utils::history_util::record("prune_workspaces", &profile.id, false, &e);
+            Err(e)
+        }
     }
 }
 
-// 重启 Cursor 应用
+// 推送新日志行的事件名
+const CURSOR_LOG_EVENT: &str = "cursor-log-lines";
+
+// 正在运行的日志 tail 任务的停止标记
+#[derive(Default)]
+struct LogTailState(Mutex<Option<Arc<AtomicBool>>>);
+
+// 编辑器日志会话列表（每次启动一个会话），最新的在前
 #[tauri::command]
-async fn restart_cursor() -> bool {
-    let cursor_path = get_cursor_install_info().await.install_path + "cursor.exe";
-    if cursor_path.is_empty() {
-        return false;
+async fn list_cursor_log_sessions(editor: Option<String>) -> Result<Vec<LogSession>, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    utils::editor_log_util::list_sessions(&profile)
+}
+
+// 读取并过滤编辑器日志
+#[tauri::command]
+async fn read_cursor_logs(
+    editor: Option<String>,
+    filter: Option<LogFilter>,
+) -> Result<Vec<LogLine>, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    utils::editor_log_util::read_logs(&profile, &filter.unwrap_or_default())
+}
+
+// 开始推送新写入的日志（事件 cursor-log-lines），已有任务时先停止旧任务
+#[tauri::command]
+async fn tail_cursor_logs(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, LogTailState>,
+    editor: Option<String>,
+    filter: Option<LogFilter>,
+) -> Result<(), String> {
+    let profile = editor_profile(editor.as_deref())?;
+    let filter = filter.unwrap_or_default();
+    let stop = Arc::new(AtomicBool::new(false));
+    if let Some(previous) = state.0.lock().unwrap().replace(stop.clone()) {
+        previous.store(true, Ordering::Relaxed);
     }
+    std::thread::spawn(move || {
+        utils::editor_log_util::tail(&profile, &filter, stop, |lines| {
+            let _ = app.emit(CURSOR_LOG_EVENT, lines);
+        });
+    });
+    Ok(())
+}
 
-    #[cfg(target_os = "windows")]
-    {
-        // 在 Windows 上，先尝试关闭 Cursor 进程
-        let _ = Command::new("taskkill")
-            .args(["/F", "/IM", "Cursor.exe"])
-            .creation_flags(0x08000000) // CREATE_NO_WINDOW
-            .output();
-
-        // 然后启动 Cursor
-        if let Ok(_) = Command::new(&cursor_path)
-            .creation_flags(0x08000000) // CREATE_NO_WINDOW
-            .spawn()
-        {
-            return true;
+// 停止推送日志
+#[tauri::command]
+async fn stop_tail_cursor_logs(state: tauri::State<'_, LogTailState>) -> Result<(), String> {
+    if let Some(stop) = state.0.lock().unwrap().take() {
+        stop.store(true, Ordering::Relaxed);
+    }
+    Ok(())
+}
+
+// 扫描 Crashpad 中的崩溃转储
+#[tauri::command]
+async fn list_crash_reports(editor: Option<String>) -> Result<CrashSummary, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    utils::crash_util::list_crash_dumps(&profile)
+}
+
+// 将崩溃转储打包为 zip（ids 为空时打包全部），path 为空时保存到应用数据目录
+#[tauri::command]
+async fn archive_crash_reports(
+    editor: Option<String>,
+    ids: Vec<String>,
+    path: Option<String>,
+) -> Result<String, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    let target = match path {
+        Some(path) if !path.is_empty() => std::path::PathBuf::from(path),
+        _ => utils::crash_util::default_archive_path(&profile),
+    };
+    let archive_path = utils::crash_util::archive_crash_dumps(&profile, &ids, &target)?;
+    utils::log_util::info(&t_args("log.archive_crashes", &[&archive_path]));
+    Ok(archive_path)
+}
+
+// 删除崩溃转储（ids 为空时删除全部），返回删除的数量
+#[tauri::command]
+async fn delete_crash_reports(editor: Option<String>, ids: Vec<String>) -> Result<usize, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    let count = utils::crash_util::delete_crash_dumps(&profile, &ids)?;
+    let message = t_args("log.delete_crashes", &[&count.to_string()]);
+    utils::log_util::info(&message);
+    utils::history_util::record("delete_crashes", &profile.id, true, &message);
+    Ok(count)
+}
+
+// 最近打开的文件夹、工作区和文件
+#[tauri::command]
+async fn list_recent_workspaces(editor: Option<String>) -> Result<Vec<RecentEntry>, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    utils::recent_util::list_recent(&profile)
+}
+
+// 修改最近打开列表：先确认编辑器未运行，修改前备份 state.vscdb，结果记入日志和历史
+fn update_recent(
+    profile: &EditorProfile,
+    update: impl FnOnce(&EditorProfile) -> Result<RecentUpdateResult, String>,
+) -> Result<RecentUpdateResult, String> {
+    ensure_not_running(profile)?;
+    match update(profile) {
+        Ok(result) => {
+            let message = t_args(
+                "log.update_recent",
+                &[&result.removed.to_string(), &result.backup_path],
+            );
+            utils::log_util::info(&message);
+            utils::history_util::record("update_recent", &profile.id, true, &message);
+            Ok(result)
+        }
+        Err(e) => {
+            utils::log_util::error(&e);
+            utils::history_util::record("update_recent", &profile.id, false, &e);
+            Err(e)
         }
     }
+}
 
-    #[cfg(target_os = "macos")]
-    {
-        // 在 macOS 上，先尝试关闭 Cursor 进程
-        let _ = Command::new("killall").args(["Cursor"]).output();
+// 从最近打开列表中删除指定条目（按 URI）
+#[tauri::command]
+async fn remove_recent_workspaces(
+    editor: Option<String>,
+    uris: Vec<String>,
+) -> Result<RecentUpdateResult, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    update_recent(&profile, |profile| {
+        utils::recent_util::remove_recent(profile, &uris)
+    })
+}
 
-        // 等待一秒确保进程完全关闭
-        std::thread::sleep(std::time::Duration::from_secs(1));
+// 删除本地路径已不存在的条目
+#[tauri::command]
+async fn remove_missing_recent_workspaces(
+    editor: Option<String>,
+) -> Result<RecentUpdateResult, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    update_recent(&profile, utils::recent_util::remove_missing_recent)
+}
 
-        // 如果路径以 .app 结尾，使用 open 命令
-        if cursor_path.ends_with(".app") {
-            if let Ok(_) = Command::new("open").args(["-a", &cursor_path]).output() {
-                return true;
-            }
-        } else {
-            // 如果不是 .app 路径，尝试直接打开 Cursor.app
-            if let Ok(_) = Command::new("open").args(["-a", "Cursor"]).output() {
-                return true;
-            }
+// 清空最近打开列表
+#[tauri::command]
+async fn clear_recent_workspaces(editor: Option<String>) -> Result<RecentUpdateResult, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    update_recent(&profile, utils::recent_util::clear_recent)
+}
+
+// 按工作区列出热退出保存的未保存文件
+#[tauri::command]
+async fn list_hot_exit_backups(editor: Option<String>) -> Result<Vec<HotExitWorkspace>, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    utils::hot_exit_util::list_hot_exit_backups(&profile)
+}
+
+// 将选中的未保存文件导出到指定文件夹，返回导出的文件路径
+#[tauri::command]
+async fn export_hot_exit_backups(
+    editor: Option<String>,
+    ids: Vec<String>,
+    path: String,
+) -> Result<Vec<String>, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    let exported =
+        utils::hot_exit_util::export_hot_exit_backups(&profile, &ids, std::path::Path::new(&path))?;
+    utils::log_util::info(&t_args(
+        "log.export_hot_exit",
+        &[&exported.len().to_string(), &path],
+    ));
+    Ok(exported)
+}
+
+// 隔离配置列表，机器码按指定方式遮盖
+#[tauri::command]
+async fn list_cursor_profiles(
+    editor: Option<String>,
+    mask: Option<MaskMode>,
+) -> Result<Vec<IsolatedProfile>, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    Ok(utils::profile_util::list_profiles(
+        &profile,
+        mask.unwrap_or_default(),
+    ))
+}
+
+// 新建空白的隔离配置
+#[tauri::command]
+async fn create_cursor_profile(
+    editor: Option<String>,
+    name: String,
+) -> Result<IsolatedProfile, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    utils::profile_util::create_profile(&profile, &name, MaskMode::default())
+}
+
+// 复制已有的隔离配置
+#[tauri::command]
+async fn clone_cursor_profile(
+    editor: Option<String>,
+    source: String,
+    name: String,
+) -> Result<IsolatedProfile, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    utils::profile_util::clone_profile(&profile, &source, &name, MaskMode::default())
+}
+
+// 删除隔离配置
+#[tauri::command]
+async fn delete_cursor_profile(editor: Option<String>, name: String) -> Result<(), String> {
+    let profile = editor_profile(editor.as_deref())?;
+    utils::profile_util::delete_profile(&profile, &name)?;
+    let message = t_args("log.delete_profile", &[&name]);
+    utils::log_util::info(&message);
+    utils::history_util::record("delete_profile", &profile.id, true, &message);
+    Ok(())
+}
+
+// 以指定的隔离配置启动编辑器（新实例，不影响正在运行的默认配置）
+#[tauri::command]
+async fn launch_cursor_profile(editor: Option<String>, name: String) -> Result<bool, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    let args = utils::profile_util::launch_args(&profile, &name)?;
+    let install_info = utils::install_util::find_install_info(&profile);
+    let cursor_path = utils::install_util::get_executable_path(&profile, &install_info);
+    if cursor_path.is_empty() {
+        return Err(t_args("error.executable_not_found", &[&profile.name]));
+    }
+    utils::log_util::info(&t_args("log.launch_profile", &[&name, &cursor_path]));
+    Ok(utils::process_util::launch(
+        &profile,
+        &cursor_path,
+        &args,
+        &[],
+    ))
+}
+
+// 本工具的操作历史，最新的在前
+#[tauri::command]
+async fn get_history() -> Vec<HistoryEntry> {
+    let mut history = utils::history_util::load_history();
+    history.reverse();
+    history
+}
+
+// 列出全局状态库 state.vscdb 中的键（只读副本，不影响正在运行的编辑器）
+#[tauri::command]
+async fn list_state_items(editor: Option<String>) -> Result<Vec<StateItem>, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    let db_path = utils::state_db_util::get_global_state_db_path(&profile)
+        .ok_or_else(|| t("error.state_db_not_found"))?;
+    utils::state_db_util::list_items(&db_path)
+}
+
+// 查看 state.vscdb 中某个键的值，令牌、凭据等敏感内容自动遮盖
+#[tauri::command]
+async fn get_state_value(editor: Option<String>, key: String) -> Result<StateValue, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    let db_path = utils::state_db_util::get_global_state_db_path(&profile)
+        .ok_or_else(|| t("error.state_db_not_found"))?;
+    utils::state_db_util::read_redacted_item(&db_path, &key)
+}
+
+// 备份后对全局和各工作区的 state.vscdb 做完整性检查和 VACUUM，编辑器运行时拒绝执行
+#[tauri::command]
+async fn maintain_state_databases(editor: Option<String>) -> Result<MaintenanceReport, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    ensure_not_running(&profile)?;
+    match utils::maintenance_util::maintain_state_dbs(&profile) {
+        Ok(report) => {
+            let message = t_args(
+                "log.maintain_state",
+                &[
+                    &report.databases.len().to_string(),
+                    &report.size_before.to_string(),
+                    &report.size_after.to_string(),
+                    &report.backup_path,
+                ],
+            );
+            utils::log_util::info(&message);
+            let success = report
+                .databases
+                .iter()
+                .all(|db| db.integrity_ok && db.error.is_empty());
+            utils::history_util::record("maintain_state", &profile.id, success, &message);
+            Ok(report)
+        }
+        Err(e) => {
+            utils::history_util::record("maintain_state", &profile.id, false, &e);
+            Err(e)
         }
     }
+}
 
-    false
+// 健康检查：安装、数据目录、storage.json、机器码格式、文件权限、应用文件完整性、磁盘空间和残留进程
+#[tauri::command]
+async fn doctor(editor: Option<String>) -> Result<Vec<DoctorCheck>, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    Ok(utils::doctor_util::run_checks(&profile))
 }
 
-// 检测 Cursor 是否正在运行
+// 读取遥测设置（settings.json 和 argv.json）
 #[tauri::command]
-async fn is_cursor_running() -> bool {
-    #[cfg(target_os = "windows")]
-    {
-        // 在 Windows 上检查进程
-        if let Ok(output) = Command::new("tasklist")
-            .args(["/FI", "IMAGENAME eq Cursor.exe", "/NH"])
-            .creation_flags(0x08000000) // CREATE_NO_WINDOW
-            .output()
-        {
-            if let Ok(stdout) = String::from_utf8(output.stdout) {
-                return stdout.contains("Cursor.exe");
-            }
+async fn get_telemetry_settings(editor: Option<String>) -> Result<TelemetrySettings, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    utils::telemetry_util::read_telemetry_settings(&profile)
+}
+
+// 设置 telemetry.telemetryLevel：all / error / crash / off
+#[tauri::command]
+async fn set_telemetry_level(editor: Option<String>, level: String) -> Result<(), String> {
+    let profile = editor_profile(editor.as_deref())?;
+    utils::telemetry_util::set_telemetry_level(&profile, &level)?;
+    utils::log_util::info(&t_args("log.telemetry_level", &[&level]));
+    Ok(())
+}
+
+// 设置 argv.json 中的 enable-crash-reporter
+#[tauri::command]
+async fn set_crash_reporter(editor: Option<String>, enabled: bool) -> Result<(), String> {
+    let profile = editor_profile(editor.as_deref())?;
+    utils::telemetry_util::set_crash_reporter(&profile, enabled)?;
+    utils::log_util::info(&t_args("log.crash_reporter", &[&enabled.to_string()]));
+    Ok(())
+}
+
+// 一键关闭遥测：遥测级别设为 off，并关闭崩溃报告
+#[tauri::command]
+async fn apply_telemetry_baseline(editor: Option<String>) -> Result<TelemetrySettings, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    utils::telemetry_util::set_telemetry_level(&profile, "off")?;
+    utils::telemetry_util::set_crash_reporter(&profile, false)?;
+    utils::log_util::info(&t_args("log.telemetry_level", &["off"]));
+    utils::log_util::info(&t_args("log.crash_reporter", &["false"]));
+    utils::telemetry_util::read_telemetry_settings(&profile)
+}
+
+// 机器码与遥测 / 隐私设置汇总
+#[tauri::command]
+async fn get_privacy_overview(
+    editor: Option<String>,
+    mask: Option<MaskMode>,
+) -> Result<PrivacyOverview, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    let telemetry = utils::telemetry_util::read_telemetry_settings(&profile)?;
+    let device_info = get_device_info(editor, mask).await;
+    Ok(PrivacyOverview {
+        device_info,
+        telemetry,
+    })
+}
+
+// storage.json 的完整结构，包含每个节点的类型和大小
+#[tauri::command]
+async fn list_storage_keys(editor: Option<String>) -> Result<Vec<StorageEntry>, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    utils::storage_util::list_storage_keys(&profile)
+}
+
+// 按 JSON Pointer 读取 storage.json 中的值（只读），机器码默认部分遮盖
+#[tauri::command]
+async fn get_storage_value(
+    editor: Option<String>,
+    path: String,
+    mask: Option<MaskMode>,
+) -> Result<serde_json::Value, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    utils::storage_util::get_storage_value(&profile, &path, mask.unwrap_or_default())
+}
+
+// 在 storage.json 的键名和字符串值中搜索
+#[tauri::command]
+async fn search_storage(
+    editor: Option<String>,
+    query: String,
+) -> Result<Vec<StorageMatch>, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    utils::storage_util::search_storage(&profile, &query)
+}
+
+// 导出诊断包，path 为空时保存到应用数据目录，返回 zip 文件路径
+#[tauri::command]
+async fn export_diagnostics(
+    editor: Option<String>,
+    path: Option<String>,
+) -> Result<String, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    let target = match path {
+        Some(path) if !path.is_empty() => std::path::PathBuf::from(path),
+        _ => utils::diagnostics_util::default_export_path(),
+    };
+    match utils::diagnostics_util::export_diagnostics(&target, &profile) {
+        Ok(zip_path) => {
+            utils::log_util::info(&t_args("log.export_diagnostics", &[&zip_path]));
+            Ok(zip_path)
+        }
+        Err(e) => {
+            let message = t_args("error.export_diagnostics", &[&e]);
+            utils::log_util::error(&message);
+            Err(message)
         }
     }
+}
 
-    #[cfg(target_os = "macos")]
-    {
-        // 在 macOS 上检查进程
-        if let Ok(output) = Command::new("pgrep").args(["-x", "Cursor"]).output() {
-            return !output.stdout.is_empty();
+// 导出状态报告（JSON / Markdown / HTML），path 为空时保存到应用数据目录，返回文件路径
+#[tauri::command]
+async fn export_report(
+    editor: Option<String>,
+    format: Option<ReportFormat>,
+    path: Option<String>,
+    mask: Option<MaskMode>,
+) -> Result<String, String> {
+    let profile = editor_profile(editor.as_deref())?;
+    let format = format.unwrap_or_default();
+    let target = match path {
+        Some(path) if !path.is_empty() => std::path::PathBuf::from(path),
+        _ => utils::report_util::default_report_path(&profile, format),
+    };
+    match utils::report_util::export_report(&profile, format, mask.unwrap_or_default(), &target) {
+        Ok(report_path) => {
+            utils::log_util::info(&t_args("log.export_report", &[&report_path]));
+            Ok(report_path)
+        }
+        Err(e) => {
+            let message = t_args("error.export_report", &[&e]);
+            utils::log_util::error(&message);
+            Err(message)
         }
     }
+}
 
-    false
+// 读取本工具配置
+#[tauri::command]
+async fn get_app_config() -> AppConfig {
+    utils::config_util::load_config()
+}
+
+// 保存本工具配置，同步更新窗口标题、托盘文案和开机启动
+#[tauri::command]
+async fn set_app_config(app: tauri::AppHandle, config: AppConfig) -> bool {
+    let saved = utils::config_util::save_config(&config);
+    if saved {
+        apply_window_title(&app);
+        tray::refresh_tray(&app);
+        apply_launch_at_login(&app, &config);
+    }
+    saved
+}
+
+// 当前生效的后端语言
+#[tauri::command]
+async fn get_language() -> String {
+    utils::i18n_util::current_language().code().to_string()
+}
+
+fn apply_window_title(app: &tauri::AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.set_title(&t("app.title"));
+    }
+}
+
+fn apply_launch_at_login(app: &tauri::AppHandle, config: &AppConfig) {
+    let autolaunch = app.autolaunch();
+    let enabled = autolaunch.is_enabled().unwrap_or(false);
+    if config.launch_at_login && !enabled {
+        let _ = autolaunch.enable();
+    } else if !config.launch_at_login && enabled {
+        let _ = autolaunch.disable();
+    }
+}
+
+// 显示并聚焦主窗口
+fn show_main_window(app: &tauri::AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.show();
+        let _ = window.unminimize();
+        let _ = window.set_focus();
+    }
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
+        .plugin(tauri_plugin_autostart::init(
+            MacosLauncher::LaunchAgent,
+            Some(vec![tray::MINIMIZED_ARG]),
+        ))
+        .manage(LogTailState::default())
+        .setup(|app| {
+            let config = utils::config_util::load_config();
+            apply_window_title(app.handle());
+            apply_launch_at_login(app.handle(), &config);
+            tray::create_tray(app.handle())?;
+            // 主窗口默认隐藏，按配置或开机启动参数决定是否显示
+            let launched_minimized = std::env::args().any(|arg| arg == tray::MINIMIZED_ARG);
+            if !config.start_minimized && !launched_minimized {
+                show_main_window(app.handle());
+            }
+            Ok(())
+        })
+        .on_window_event(|window, event| {
+            if let tauri::WindowEvent::CloseRequested { api, .. } = event {
+                if utils::config_util::load_config().close_to_tray {
+                    let _ = window.hide();
+                    api.prevent_close();
+                }
+            }
+        })
         .invoke_handler(tauri::generate_handler![
+            list_editor_profiles,
             get_device_info,
+            reveal_device_info,
+            backup_device_info,
             get_cursor_install_info,
             reset_device_info,
             restart_cursor,
-            is_cursor_running
+            launch_cursor,
+            is_cursor_running,
+            list_storage_keys,
+            get_storage_value,
+            search_storage,
+            list_extensions,
+            export_extensions,
+            compare_extensions,
+            create_settings_snapshot,
+            list_settings_snapshots,
+            restore_settings_snapshot,
+            disk_usage,
+            clean,
+            list_workspace_storage,
+            prune_workspace_storage,
+            list_cursor_log_sessions,
+            read_cursor_logs,
+            tail_cursor_logs,
+            stop_tail_cursor_logs,
+            list_crash_reports,
+            archive_crash_reports,
+            delete_crash_reports,
+            list_recent_workspaces,
+            remove_recent_workspaces,
+            remove_missing_recent_workspaces,
+            clear_recent_workspaces,
+            list_hot_exit_backups,
+            export_hot_exit_backups,
+            list_cursor_profiles,
+            create_cursor_profile,
+            clone_cursor_profile,
+            delete_cursor_profile,
+            launch_cursor_profile,
+            get_history,
+            list_state_items,
+            get_state_value,
+            maintain_state_databases,
+            doctor,
+            get_telemetry_settings,
+            set_telemetry_level,
+            set_crash_reporter,
+            apply_telemetry_baseline,
+            get_privacy_overview,
+            get_app_config,
+            set_app_config,
+            get_language,
+            export_diagnostics,
+            export_report
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -1,6 +1,14 @@
 // Prevents additional console window on Windows in release, DO NOT REMOVE!!
-#![cfg_attr(all(not(debug_assertions), target_os = "windows"), windows_subsystem = "windows")]
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
 
 fn main() {
+    // 带子命令（例如 report）启动时只执行命令行功能，不打开窗口
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let Some(code) = cursor_reset_lib::run_cli(&args) {
+        std::process::exit(code);
+    }
     cursor_reset_lib::run()
 }
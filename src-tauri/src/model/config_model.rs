@@ -0,0 +1,24 @@
+use crate::model::hook_model::HooksConfig;
+use crate::model::settings_backup_model::SettingsBackupMode;
+
+// 本工具自身的配置，保存在应用数据目录下的 config.json
+#[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct AppConfig {
+    // 是否允许从 Cursor 日志、Sentry 文件等位置收集用户名和邮箱，默认关闭
+    pub collect_personal_data: bool,
+    // 后端消息语言："zh-CN" / "en"，为空时跟随系统语言
+    pub language: String,
+    // 启动时只显示托盘图标，不显示主窗口
+    pub start_minimized: bool,
+    // 开机自动启动
+    pub launch_at_login: bool,
+    // 关闭主窗口时隐藏到托盘而不是退出
+    pub close_to_tray: bool,
+    // 配置快照保存位置，为空时使用应用数据目录下的 settings-backups
+    pub settings_backup_dir: String,
+    // 配置快照保存方式：folder / git
+    pub settings_backup_mode: SettingsBackupMode,
+    // 重置、重启前后执行的钩子命令
+    pub hooks: HooksConfig,
+}
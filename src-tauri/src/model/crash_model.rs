@@ -0,0 +1,22 @@
+// Crashpad 生成的一个崩溃转储
+#[derive(Clone, serde::Serialize)]
+pub struct CrashDump {
+    // 相对 Crashpad 目录的路径，例如 completed/xxxx.dmp
+    pub id: String,
+    pub path: String,
+    // 所在子目录：completed / pending / new / reports 等
+    pub state: String,
+    pub created_at: String,
+    pub size: u64,
+    // 从转储中读取的编辑器版本，读取不到时为空
+    pub version: String,
+}
+
+// 崩溃转储概要
+#[derive(Clone, serde::Serialize)]
+pub struct CrashSummary {
+    pub dir: String,
+    pub count: usize,
+    pub total_size: u64,
+    pub dumps: Vec<CrashDump>,
+}
@@ -1,3 +1,40 @@
+use sha2::{Digest, Sha256};
+use std::fmt;
+
+// 机器码展示方式：完整、部分遮盖、哈希
+#[derive(Clone, Copy, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MaskMode {
+    Full,
+    #[default]
+    Partial,
+    Hashed,
+}
+
+// 按展示方式处理单个标识符
+pub fn mask_value(value: &str, mode: MaskMode) -> String {
+    if value.is_empty() {
+        return "".to_string();
+    }
+    match mode {
+        MaskMode::Full => value.to_string(),
+        MaskMode::Partial => {
+            let chars: Vec<char> = value.chars().collect();
+            if chars.len() <= 8 {
+                return "*".repeat(chars.len());
+            }
+            let head: String = chars[..4].iter().collect();
+            let tail: String = chars[chars.len() - 4..].iter().collect();
+            format!("{}****{}", head, tail)
+        }
+        MaskMode::Hashed => {
+            let digest = Sha256::digest(value.as_bytes());
+            let hex: String = digest.iter().map(|b| format!("{:02x}", b)).collect();
+            format!("sha256:{}", &hex[..12])
+        }
+    }
+}
+
 #[derive(Clone, serde::Serialize)]
 pub struct CursorDeviceInfo {
     pub mac_machine_id: String,
@@ -17,9 +54,30 @@ impl CursorDeviceInfo {
     pub fn reset(self)-> CursorDeviceInfo {
         return self
     }
-    pub fn blocking_kind(&self) -> String {
-        // 方法实现
-        return format!("{}:{}:{}:{}", self.mac_machine_id.clone(), self.machine_id.clone(), self.sqm_id.clone(), self.dev_device_id.clone());
+    pub fn blocking_kind(&self, mode: MaskMode) -> String {
+        let info = self.masked(mode);
+        format!("{}:{}:{}:{}", info.mac_machine_id, info.machine_id, info.sqm_id, info.dev_device_id)
+    }
+
+    // 返回按指定方式处理后的副本，用于展示、截图和导出
+    pub fn masked(&self, mode: MaskMode) -> CursorDeviceInfo {
+        CursorDeviceInfo::new(
+            mask_value(&self.mac_machine_id, mode),
+            mask_value(&self.machine_id, mode),
+            mask_value(&self.sqm_id, mode),
+            mask_value(&self.dev_device_id, mode),
+        )
+    }
+}
+
+// 默认以部分遮盖的形式输出，避免复制时泄露完整机器码
+impl fmt::Display for CursorDeviceInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let info = self.masked(MaskMode::Partial);
+        writeln!(f, "mac_machine_id: {}", info.mac_machine_id)?;
+        writeln!(f, "machine_id: {}", info.machine_id)?;
+        writeln!(f, "sqm_id: {}", info.sqm_id)?;
+        write!(f, "dev_device_id: {}", info.dev_device_id)
     }
 }
 
@@ -0,0 +1,24 @@
+// 候选 storage.json 路径的检查结果
+#[derive(Clone, serde::Serialize)]
+pub struct PathCheck {
+    pub path: String,
+    pub exists: bool,
+    pub is_file: bool,
+    pub readonly: bool,
+    pub writable: bool,
+    pub size: u64,
+}
+
+// 诊断包中的概要信息
+#[derive(Clone, serde::Serialize)]
+pub struct DiagnosticsSummary {
+    pub app_version: String,
+    pub editor: String,
+    pub os: String,
+    pub arch: String,
+    pub generated_at: String,
+    pub cursor_running: bool,
+    pub package_path_found: bool,
+    // Crashpad 中的崩溃转储数量
+    pub crash_count: usize,
+}
@@ -0,0 +1,62 @@
+// 编辑器数据目录中可清理的缓存类别
+#[derive(Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CacheCategory {
+    Cache,
+    CachedData,
+    GpuCache,
+    Logs,
+    WorkspaceStorage,
+    CachedExtensionVsixs,
+}
+
+impl CacheCategory {
+    pub const ALL: [CacheCategory; 6] = [
+        CacheCategory::Cache,
+        CacheCategory::CachedData,
+        CacheCategory::GpuCache,
+        CacheCategory::Logs,
+        CacheCategory::WorkspaceStorage,
+        CacheCategory::CachedExtensionVsixs,
+    ];
+
+    // 相对编辑器数据目录的路径
+    pub fn relative_path(&self) -> &'static str {
+        match self {
+            CacheCategory::Cache => "Cache",
+            CacheCategory::CachedData => "CachedData",
+            CacheCategory::GpuCache => "GPUCache",
+            CacheCategory::Logs => "logs",
+            CacheCategory::WorkspaceStorage => "User/workspaceStorage",
+            CacheCategory::CachedExtensionVsixs => "CachedExtensionVSIXs",
+        }
+    }
+}
+
+// 单个类别的占用情况
+#[derive(Clone, serde::Serialize)]
+pub struct CategoryUsage {
+    pub category: CacheCategory,
+    pub path: String,
+    pub exists: bool,
+    pub size: u64,
+}
+
+// 数据目录的占用情况
+#[derive(Clone, serde::Serialize)]
+pub struct DiskUsage {
+    pub data_dir: String,
+    // 整个数据目录的大小（包含未列出的部分）
+    pub total: u64,
+    pub categories: Vec<CategoryUsage>,
+}
+
+// 清理结果
+#[derive(Clone, Default, serde::Serialize)]
+pub struct CleanResult {
+    // 实际释放的字节数
+    pub freed: u64,
+    pub cleaned: Vec<CacheCategory>,
+    // 无法删除的文件（通常是被占用）
+    pub failed: Vec<String>,
+}
@@ -0,0 +1,20 @@
+#[derive(Clone, Copy, PartialEq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+// 一项健康检查的结果
+#[derive(Clone, serde::Serialize)]
+pub struct DoctorCheck {
+    // 检查项标识，例如 install_found
+    pub id: String,
+    pub title: String,
+    pub status: CheckStatus,
+    // 检查细节（路径、数量等）
+    pub detail: String,
+    // 未通过时的处理建议
+    pub remedy: String,
+}
@@ -0,0 +1,51 @@
+// 编辑器 logs 目录下的一个日志文件
+#[derive(Clone, serde::Serialize)]
+pub struct LogFileInfo {
+    // 相对会话目录的路径，例如 window1/renderer.log
+    pub file: String,
+    // 频道，取文件名（不含扩展名），例如 main / renderer / exthost
+    pub channel: String,
+    // 所属窗口目录，例如 window1，主进程日志为空
+    pub window: String,
+    pub size: u64,
+    pub modified_at: String,
+}
+
+// 一次编辑器启动对应的日志会话，目录名形如 20250101T080000
+#[derive(Clone, serde::Serialize)]
+pub struct LogSession {
+    pub id: String,
+    pub path: String,
+    pub started_at: String,
+    pub size: u64,
+    pub windows: Vec<String>,
+    pub files: Vec<LogFileInfo>,
+}
+
+// 解析后的一条日志
+#[derive(Clone, serde::Serialize)]
+pub struct LogLine {
+    pub session: String,
+    pub file: String,
+    pub channel: String,
+    pub line_number: usize,
+    pub timestamp: String,
+    pub level: String,
+    // 多行日志（例如堆栈）会合并到同一条的 message 中
+    pub message: String,
+}
+
+// 日志过滤条件，字段为空表示不过滤
+#[derive(Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct LogFilter {
+    // 会话 id，为空时使用最新的会话
+    pub session: String,
+    pub window: String,
+    pub channels: Vec<String>,
+    pub levels: Vec<String>,
+    // 不区分大小写的关键字
+    pub query: String,
+    // 最多返回的条数（取最新的），0 表示默认值
+    pub limit: usize,
+}
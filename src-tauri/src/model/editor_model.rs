@@ -0,0 +1,74 @@
+// 按操作系统区分的一组路径
+#[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct PlatformPaths {
+    pub windows: Vec<String>,
+    pub macos: Vec<String>,
+}
+
+impl PlatformPaths {
+    // 当前系统对应的路径列表
+    pub fn current(&self) -> &Vec<String> {
+        #[cfg(target_os = "windows")]
+        {
+            &self.windows
+        }
+
+        #[cfg(target_os = "macos")]
+        {
+            &self.macos
+        }
+    }
+}
+
+// storage.json 中机器码对应的键名
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct TelemetryKeys {
+    pub mac_machine_id: String,
+    pub machine_id: String,
+    pub sqm_id: String,
+    pub dev_device_id: String,
+}
+
+impl Default for TelemetryKeys {
+    fn default() -> Self {
+        Self {
+            mac_machine_id: "telemetry.macMachineId".to_string(),
+            machine_id: "telemetry.machineId".to_string(),
+            sqm_id: "telemetry.sqmId".to_string(),
+            dev_device_id: "telemetry.devDeviceId".to_string(),
+        }
+    }
+}
+
+// 一种 VS Code 系编辑器的描述，相对路径均相对于用户根目录（安装目录下的文件除外）
+#[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct EditorProfile {
+    pub id: String,
+    pub name: String,
+    // 用户数据目录，例如 AppData\Roaming\Cursor
+    pub data_dirs: PlatformPaths,
+    // storage.json 的候选位置，按顺序查找
+    pub storage_files: PlatformPaths,
+    // 用户根目录下的配置目录，例如 .cursor（argv.json 和扩展所在目录）
+    pub home_config_dir: String,
+    // 进程名，用于检测运行状态和结束进程
+    pub process_names: PlatformPaths,
+    // Windows 卸载信息注册表项（HKEY_CURRENT_USER 下）
+    pub windows_registry_keys: Vec<String>,
+    // Windows 下安装目录中的可执行文件名
+    pub windows_executable: String,
+    // macOS 下 .app 的候选位置
+    pub macos_app_paths: Vec<String>,
+    // macOS 下通过 mdfind 查找应用时使用的 Bundle ID
+    pub macos_bundle_id: String,
+    // product.json 相对安装目录的位置
+    pub product_json: PlatformPaths,
+    // 需要替换硬件序列号读取命令的 main.js
+    pub main_js: PlatformPaths,
+    // 命令行工具相对安装目录的位置，用于安装扩展
+    pub cli: PlatformPaths,
+    pub telemetry_keys: TelemetryKeys,
+}
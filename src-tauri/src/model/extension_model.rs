@@ -0,0 +1,44 @@
+// 本地安装的扩展
+#[derive(Clone, serde::Serialize)]
+pub struct ExtensionInfo {
+    // publisher.name，统一小写
+    pub id: String,
+    pub display_name: String,
+    pub publisher: String,
+    pub version: String,
+    pub size: u64,
+    pub enabled: bool,
+}
+
+// 导出清单中的一项
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+pub struct ManifestEntry {
+    pub id: String,
+    pub version: String,
+}
+
+// 可移植的扩展清单文件
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+pub struct ExtensionManifest {
+    pub editor: String,
+    pub exported_at: String,
+    pub extensions: Vec<ManifestEntry>,
+}
+
+// 版本不一致的扩展
+#[derive(Clone, serde::Serialize)]
+pub struct VersionMismatch {
+    pub id: String,
+    pub local_version: String,
+    pub manifest_version: String,
+}
+
+// 本地扩展与清单的对比结果
+#[derive(Clone, serde::Serialize)]
+pub struct ExtensionDiff {
+    // 清单中有、本地没有
+    pub missing: Vec<ManifestEntry>,
+    pub version_mismatch: Vec<VersionMismatch>,
+    // 本地有、清单中没有
+    pub extra: Vec<ManifestEntry>,
+}
@@ -0,0 +1,10 @@
+// 本工具执行过的操作记录，保存在应用数据目录下的 history.json
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+pub struct HistoryEntry {
+    pub time: String,
+    // 操作名，例如 reset / clean
+    pub action: String,
+    pub editor: String,
+    pub success: bool,
+    pub detail: String,
+}
@@ -0,0 +1,96 @@
+// 可以挂接命令的操作节点
+#[derive(Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HookEvent {
+    BeforeReset,
+    AfterReset,
+    BeforeRestart,
+    AfterRestart,
+}
+
+impl HookEvent {
+    pub fn name(&self) -> &'static str {
+        match self {
+            HookEvent::BeforeReset => "before_reset",
+            HookEvent::AfterReset => "after_reset",
+            HookEvent::BeforeRestart => "before_restart",
+            HookEvent::AfterRestart => "after_restart",
+        }
+    }
+
+    // 前置钩子失败时取消操作
+    pub fn is_pre(&self) -> bool {
+        matches!(self, HookEvent::BeforeReset | HookEvent::BeforeRestart)
+    }
+}
+
+// 一条钩子命令，通过系统 shell 执行（Windows 为 cmd /C，macOS 为 sh -c）
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct HookCommand {
+    pub command: String,
+    // 超时时间（秒），超时后结束命令并视为失败
+    pub timeout_secs: u64,
+}
+
+impl Default for HookCommand {
+    fn default() -> Self {
+        Self {
+            command: "".to_string(),
+            timeout_secs: 30,
+        }
+    }
+}
+
+// 各操作节点的钩子命令，未配置或命令为空时跳过
+#[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct HooksConfig {
+    pub before_reset: Option<HookCommand>,
+    pub after_reset: Option<HookCommand>,
+    pub before_restart: Option<HookCommand>,
+    pub after_restart: Option<HookCommand>,
+}
+
+impl HooksConfig {
+    pub fn get(&self, event: HookEvent) -> Option<&HookCommand> {
+        let hook = match event {
+            HookEvent::BeforeReset => &self.before_reset,
+            HookEvent::AfterReset => &self.after_reset,
+            HookEvent::BeforeRestart => &self.before_restart,
+            HookEvent::AfterRestart => &self.after_restart,
+        };
+        hook.as_ref().filter(|hook| !hook.command.trim().is_empty())
+    }
+}
+
+// 通过标准输入以 JSON 形式传给钩子命令的操作上下文
+#[derive(Clone, serde::Serialize)]
+pub struct HookContext {
+    pub event: HookEvent,
+    pub editor: String,
+    pub editor_name: String,
+    pub time: String,
+    // 操作对象，例如 storage.json 路径或编辑器主程序路径
+    pub target: String,
+    // 后置钩子中为操作是否成功，前置钩子中为空
+    pub success: Option<bool>,
+}
+
+// 钩子命令的执行结果
+#[derive(Clone, serde::Serialize)]
+pub struct HookResult {
+    pub event: HookEvent,
+    pub command: String,
+    // 被超时结束时为空
+    pub exit_code: Option<i32>,
+    pub timed_out: bool,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl HookResult {
+    pub fn success(&self) -> bool {
+        !self.timed_out && self.exit_code == Some(0)
+    }
+}
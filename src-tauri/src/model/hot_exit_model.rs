@@ -0,0 +1,25 @@
+// Backups 目录中一个未保存文件的备份
+#[derive(Clone, serde::Serialize)]
+pub struct HotExitBackup {
+    // 相对 Backups 目录的路径，例如 <工作区>/file/<哈希>
+    pub id: String,
+    pub path: String,
+    // file / untitled 等
+    pub scheme: String,
+    // 备份对应的原始文件 URI，未命名文件为 untitled:Untitled-1
+    pub original_uri: String,
+    pub size: u64,
+    pub modified_at: String,
+    // 内容开头的一小段
+    pub preview: String,
+}
+
+// 一个工作区（或空窗口）下的全部备份
+#[derive(Clone, serde::Serialize)]
+pub struct HotExitWorkspace {
+    // Backups 下的目录名，与 workspaceStorage 下的目录名一致
+    pub id: String,
+    // 工作区文件夹或 .code-workspace 的 URI，空窗口为空
+    pub workspace_uri: String,
+    pub backups: Vec<HotExitBackup>,
+}
@@ -0,0 +1,21 @@
+use std::collections::BTreeMap;
+
+// 启动编辑器的选项
+#[derive(Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct LaunchOptions {
+    // 要打开的文件夹
+    pub folders: Vec<String>,
+    // 要打开的文件
+    pub files: Vec<String>,
+    // 强制打开新窗口
+    pub new_window: bool,
+    pub disable_extensions: bool,
+    pub verbose: bool,
+    // --log 的级别：critical / error / warn / info / debug / trace / off，为空时不传
+    pub log_level: String,
+    // 为空时使用默认的用户数据目录
+    pub user_data_dir: String,
+    // 额外的环境变量
+    pub env: BTreeMap<String, String>,
+}
@@ -0,0 +1,26 @@
+// 单个 state.vscdb 的维护结果
+#[derive(Clone, Default, serde::Serialize)]
+pub struct DbMaintenanceResult {
+    // global 或 workspaceStorage 下的工作区 id
+    pub scope: String,
+    pub path: String,
+    pub size_before: u64,
+    pub size_after: u64,
+    pub integrity_ok: bool,
+    // integrity_check 报告的问题
+    pub integrity_errors: Vec<String>,
+    // 完整性检查未通过时不执行 VACUUM
+    pub vacuumed: bool,
+    // 打开或执行失败时的错误信息
+    pub error: String,
+}
+
+// 一次维护的整体结果
+#[derive(Clone, Default, serde::Serialize)]
+pub struct MaintenanceReport {
+    // 维护前全部数据库的备份目录
+    pub backup_path: String,
+    pub size_before: u64,
+    pub size_after: u64,
+    pub databases: Vec<DbMaintenanceResult>,
+}
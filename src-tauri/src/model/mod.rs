@@ -1 +1,22 @@
-pub mod cursor_model;
\ No newline at end of file
+pub mod config_model;
+pub mod crash_model;
+pub mod cursor_model;
+pub mod diagnostics_model;
+pub mod disk_model;
+pub mod doctor_model;
+pub mod editor_log_model;
+pub mod editor_model;
+pub mod extension_model;
+pub mod history_model;
+pub mod hook_model;
+pub mod hot_exit_model;
+pub mod launch_model;
+pub mod maintenance_model;
+pub mod profile_model;
+pub mod recent_model;
+pub mod report_model;
+pub mod settings_backup_model;
+pub mod state_model;
+pub mod storage_model;
+pub mod telemetry_model;
+pub mod workspace_model;
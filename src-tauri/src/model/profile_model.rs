@@ -0,0 +1,15 @@
+use crate::model::cursor_model::CursorDeviceInfo;
+
+// 使用独立 --user-data-dir / --extensions-dir 的隔离配置
+#[derive(Clone, serde::Serialize)]
+pub struct IsolatedProfile {
+    pub name: String,
+    pub editor: String,
+    pub path: String,
+    pub user_data_dir: String,
+    pub extensions_dir: String,
+    pub size: u64,
+    pub created_at: String,
+    // 尚未启动过的配置没有 storage.json
+    pub device_info: Option<CursorDeviceInfo>,
+}
@@ -0,0 +1,24 @@
+// 最近打开列表中的一项
+#[derive(Clone, serde::Serialize)]
+pub struct RecentEntry {
+    // folder / workspace / file
+    pub kind: String,
+    // 文件夹、.code-workspace 文件或文件的 URI，同时作为删除时的标识
+    pub uri: String,
+    pub label: String,
+    // 远程工作区的主机标识，例如 ssh-remote+server，本地为空
+    pub remote_authority: String,
+    // 本地文件 URI 对应的路径，远程条目为空
+    pub local_path: String,
+    // 本地路径是否存在，远程条目视为存在
+    pub exists: bool,
+}
+
+// 修改最近打开列表后的结果
+#[derive(Clone, serde::Serialize)]
+pub struct RecentUpdateResult {
+    pub removed: usize,
+    // 修改前 state.vscdb 的备份目录
+    pub backup_path: String,
+    pub entries: Vec<RecentEntry>,
+}
@@ -0,0 +1,49 @@
+use crate::model::cursor_model::{CursorDeviceInfo, CursorInstallInfo};
+use crate::model::doctor_model::DoctorCheck;
+use crate::model::history_model::HistoryEntry;
+
+#[derive(Clone, Copy, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportFormat {
+    #[default]
+    Json,
+    Markdown,
+    Html,
+}
+
+impl ReportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ReportFormat::Json => "json",
+            ReportFormat::Markdown => "md",
+            ReportFormat::Html => "html",
+        }
+    }
+
+    // 命令行参数：json / md / markdown / html
+    pub fn parse(value: &str) -> Option<ReportFormat> {
+        match value.to_lowercase().as_str() {
+            "json" => Some(ReportFormat::Json),
+            "md" | "markdown" => Some(ReportFormat::Markdown),
+            "html" | "htm" => Some(ReportFormat::Html),
+            _ => None,
+        }
+    }
+}
+
+// 可分享的状态报告，安装信息已脱敏、机器码已遮盖
+#[derive(Clone, serde::Serialize)]
+pub struct Report {
+    pub generated_at: String,
+    pub app_version: String,
+    pub editor: String,
+    pub os: String,
+    pub arch: String,
+    pub cursor_running: bool,
+    pub install_info: CursorInstallInfo,
+    // 未找到 storage.json 时为空
+    pub device_info: Option<CursorDeviceInfo>,
+    pub checks: Vec<DoctorCheck>,
+    // 最近的操作历史，最新的在前
+    pub history: Vec<HistoryEntry>,
+}
@@ -0,0 +1,60 @@
+// 可备份的用户配置项
+#[derive(Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SettingsItem {
+    Settings,
+    Keybindings,
+    Snippets,
+    Extensions,
+}
+
+impl SettingsItem {
+    pub const ALL: [SettingsItem; 4] = [
+        SettingsItem::Settings,
+        SettingsItem::Keybindings,
+        SettingsItem::Snippets,
+        SettingsItem::Extensions,
+    ];
+
+    // 快照中对应的文件或目录名
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            SettingsItem::Settings => "settings.json",
+            SettingsItem::Keybindings => "keybindings.json",
+            SettingsItem::Snippets => "snippets",
+            SettingsItem::Extensions => "extensions.json",
+        }
+    }
+}
+
+// 配置快照的保存方式：普通文件夹（每次一个子目录）或本地 git 仓库（每次一个提交）
+#[derive(Clone, Copy, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SettingsBackupMode {
+    #[default]
+    Folder,
+    Git,
+}
+
+// 一次配置快照
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+pub struct SettingsSnapshot {
+    // 文件夹模式下为子目录名，git 模式下为提交哈希
+    pub id: String,
+    pub editor: String,
+    pub created_at: String,
+    pub mode: SettingsBackupMode,
+    pub items: Vec<SettingsItem>,
+    // 快照所在目录（git 模式下为仓库目录）
+    pub location: String,
+}
+
+// 恢复结果
+#[derive(Clone, Default, serde::Serialize)]
+pub struct SettingsRestoreResult {
+    pub restored: Vec<SettingsItem>,
+    // 恢复前当前配置的备份目录
+    pub backup_path: String,
+    pub installed_extensions: Vec<String>,
+    pub failed_extensions: Vec<String>,
+}
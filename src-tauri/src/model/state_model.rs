@@ -0,0 +1,22 @@
+// state.vscdb 中 ItemTable 的一个键
+#[derive(Clone, serde::Serialize)]
+pub struct StateItem {
+    pub key: String,
+    // 值的字节数
+    pub size: u64,
+    // SQLite 存储类型：text / blob / integer / real / null
+    pub storage_type: String,
+    // 内容类型：object / array / string / number / boolean / null（均为 JSON）、text 或 binary
+    pub value_type: String,
+    // 键名本身表明值是敏感信息（查看时整体遮盖）
+    pub secret: bool,
+}
+
+// 脱敏后的值
+#[derive(Clone, serde::Serialize)]
+pub struct StateValue {
+    pub key: String,
+    pub size: u64,
+    pub value_type: String,
+    pub value: String,
+}
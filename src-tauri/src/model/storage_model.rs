@@ -0,0 +1,21 @@
+// storage.json 中的一个节点
+#[derive(Clone, serde::Serialize)]
+pub struct StorageEntry {
+    // JSON Pointer 路径，例如 /telemetry.machineId 或 /windowsState/lastActiveWindow
+    pub path: String,
+    pub key: String,
+    // object / array / string / number / boolean / null
+    pub value_type: String,
+    // 序列化后的字节数
+    pub size: usize,
+    pub children: Vec<StorageEntry>,
+}
+
+// 搜索结果
+#[derive(Clone, serde::Serialize)]
+pub struct StorageMatch {
+    pub path: String,
+    pub value_type: String,
+    // 字符串值的预览，其他类型为空
+    pub preview: String,
+}
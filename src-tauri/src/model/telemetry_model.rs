@@ -0,0 +1,20 @@
+use crate::model::cursor_model::CursorDeviceInfo;
+use serde_json::{Map, Value};
+
+// 编辑器的遥测相关设置
+#[derive(Clone, serde::Serialize)]
+pub struct TelemetrySettings {
+    // settings.json 中的 telemetry.telemetryLevel，未设置时为空（编辑器默认 all）
+    pub telemetry_level: String,
+    // argv.json 中的 enable-crash-reporter，未设置时为 None
+    pub enable_crash_reporter: Option<bool>,
+    // settings.json 中其他与隐私 / 遥测相关的设置项
+    pub privacy_settings: Map<String, Value>,
+}
+
+// 机器码和遥测设置的汇总，供界面一并展示
+#[derive(Clone, serde::Serialize)]
+pub struct PrivacyOverview {
+    pub device_info: CursorDeviceInfo,
+    pub telemetry: TelemetrySettings,
+}
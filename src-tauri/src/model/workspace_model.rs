@@ -0,0 +1,26 @@
+// User/workspaceStorage 下的一个工作区目录
+#[derive(Clone, serde::Serialize)]
+pub struct WorkspaceStorageEntry {
+    // 哈希目录名
+    pub id: String,
+    pub path: String,
+    // workspace.json 中记录的 folder 或 workspace URI
+    pub uri: String,
+    // folder / workspace / unknown
+    pub kind: String,
+    // 本地文件 URI 对应的路径，远程工作区为空
+    pub local_path: String,
+    pub size: u64,
+    pub modified_at: String,
+    // 对应的本地文件夹或 .code-workspace 文件已不存在
+    pub stale: bool,
+}
+
+// 清理结果
+#[derive(Clone, Default, serde::Serialize)]
+pub struct WorkspacePruneResult {
+    pub removed: Vec<String>,
+    pub freed: u64,
+    // 删除前的备份目录
+    pub backup_path: String,
+}
@@ -0,0 +1,127 @@
+use crate::utils::i18n_util::t;
+use tauri::menu::{Menu, MenuItem, PredefinedMenuItem};
+use tauri::tray::TrayIconBuilder;
+use tauri::{AppHandle, Manager, Wry};
+
+// 开机自启时附带的参数，带此参数启动时不显示主窗口
+pub const MINIMIZED_ARG: &str = "--minimized";
+
+const TRAY_ID: &str = "main";
+// 托盘状态刷新间隔（秒）
+const STATUS_INTERVAL_SECS: u64 = 3;
+
+// 托盘菜单中需要动态更新文案的菜单项
+struct TrayMenuItems {
+    status: MenuItem<Wry>,
+    show: MenuItem<Wry>,
+    backup: MenuItem<Wry>,
+    restart: MenuItem<Wry>,
+    open_logs: MenuItem<Wry>,
+    quit: MenuItem<Wry>,
+}
+
+fn status_text(running: bool) -> String {
+    if running {
+        t("tray.status_running")
+    } else {
+        t("tray.status_stopped")
+    }
+}
+
+// 创建托盘图标和菜单，并启动后台线程跟踪 Cursor 运行状态
+pub fn create_tray(app: &AppHandle) -> tauri::Result<()> {
+    let running = crate::check_cursor_running();
+    let items = TrayMenuItems {
+        status: MenuItem::with_id(app, "status", status_text(running), false, None::<&str>)?,
+        show: MenuItem::with_id(app, "show", t("tray.show"), true, None::<&str>)?,
+        backup: MenuItem::with_id(app, "backup", t("tray.backup"), true, None::<&str>)?,
+        restart: MenuItem::with_id(app, "restart", t("tray.restart"), true, None::<&str>)?,
+        open_logs: MenuItem::with_id(app, "open_logs", t("tray.open_logs"), true, None::<&str>)?,
+        quit: MenuItem::with_id(app, "quit", t("tray.quit"), true, None::<&str>)?,
+    };
+    let menu = Menu::with_items(
+        app,
+        &[
+            &items.status,
+            &PredefinedMenuItem::separator(app)?,
+            &items.show,
+            &items.backup,
+            &items.restart,
+            &items.open_logs,
+            &PredefinedMenuItem::separator(app)?,
+            &items.quit,
+        ],
+    )?;
+
+    let mut builder = TrayIconBuilder::with_id(TRAY_ID)
+        .tooltip(status_text(running))
+        .menu(&menu)
+        .show_menu_on_left_click(true)
+        .on_menu_event(|app, event| match event.id.as_ref() {
+            "show" => crate::show_main_window(app),
+            "backup" => {
+                if let Ok(profile) = crate::editor_profile(None) {
+                    let _ = crate::backup_storage(&profile);
+                }
+            }
+            "restart" => {
+                // 有未保存文件的备份时不在托盘里直接重启，打开主窗口让用户确认
+                let app = app.clone();
+                tauri::async_runtime::spawn(async move {
+                    if crate::restart_cursor(None, None).await.is_err() {
+                        crate::show_main_window(&app);
+                    }
+                });
+            }
+            "open_logs" => {
+                let log_dir = crate::utils::log_util::get_log_dir();
+                let _ = tauri_plugin_opener::open_path(log_dir, None::<&str>);
+            }
+            "quit" => app.exit(0),
+            _ => {}
+        });
+    if let Some(icon) = app.default_window_icon() {
+        builder = builder.icon(icon.clone());
+    }
+    builder.build(app)?;
+
+    app.manage(items);
+    spawn_status_watcher(app.clone(), running);
+    Ok(())
+}
+
+// 定时检测 Cursor 是否运行，状态变化时更新托盘提示和菜单
+fn spawn_status_watcher(app: AppHandle, initial: bool) {
+    std::thread::spawn(move || {
+        let mut last = initial;
+        loop {
+            std::thread::sleep(std::time::Duration::from_secs(STATUS_INTERVAL_SECS));
+            let running = crate::check_cursor_running();
+            if running != last {
+                last = running;
+                update_status(&app, running);
+            }
+        }
+    });
+}
+
+fn update_status(app: &AppHandle, running: bool) {
+    if let Some(items) = app.try_state::<TrayMenuItems>() {
+        let _ = items.status.set_text(status_text(running));
+    }
+    if let Some(tray) = app.tray_by_id(TRAY_ID) {
+        let _ = tray.set_tooltip(Some(status_text(running)));
+    }
+}
+
+// 语言切换后刷新托盘文案
+pub fn refresh_tray(app: &AppHandle) {
+    if let Some(items) = app.try_state::<TrayMenuItems>() {
+        let _ = items.show.set_text(t("tray.show"));
+        let _ = items.backup.set_text(t("tray.backup"));
+        let _ = items.restart.set_text(t("tray.restart"));
+        let _ = items.open_logs.set_text(t("tray.open_logs"));
+        let _ = items.quit.set_text(t("tray.quit"));
+    }
+    update_status(app, crate::check_cursor_running());
+}
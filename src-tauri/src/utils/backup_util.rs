@@ -0,0 +1,33 @@
+use crate::utils::config_util::get_app_data_dir;
+use crate::utils::time_util::{file_stamp, now_secs};
+use std::path::{Path, PathBuf};
+
+pub fn get_backup_root() -> PathBuf {
+    let dir = get_app_data_dir().join("backups");
+    let _ = std::fs::create_dir_all(&dir);
+    dir
+}
+
+// 为一次操作创建备份目录，例如 backups/20250101-080000-storage
+pub fn create_backup_dir(label: &str) -> Result<PathBuf, String> {
+    let dir = get_backup_root().join(format!("{}-{}", file_stamp(now_secs()), label));
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir)
+}
+
+// 将文件复制到指定备份目录，返回备份后的路径
+pub fn copy_into(path: &Path, dir: &Path) -> Result<PathBuf, String> {
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| format!("invalid path: {}", path.display()))?;
+    let target = dir.join(file_name);
+    std::fs::copy(path, &target).map_err(|e| e.to_string())?;
+    Ok(target)
+}
+
+// 备份单个文件到新的备份目录，返回备份后的路径
+pub fn backup_file(path: &str, label: &str) -> Result<String, String> {
+    let dir = create_backup_dir(label)?;
+    let target = copy_into(Path::new(path), &dir)?;
+    Ok(target.to_string_lossy().to_string())
+}
@@ -0,0 +1,52 @@
+use crate::model::config_model::AppConfig;
+use crate::utils::cursor_util::get_user_home_dir;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::PathBuf;
+
+const CONFIG_FILE_NAME: &str = "config.json";
+
+// 返回本工具的数据目录（配置、日志、备份等都放在这里），不存在时自动创建
+pub fn get_app_data_dir() -> PathBuf {
+    let home_dir = get_user_home_dir();
+
+    #[cfg(target_os = "windows")]
+    let dir = match std::env::var_os("APPDATA") {
+        Some(app_data) => PathBuf::from(app_data).join("cursor-reset"),
+        None => PathBuf::from(&home_dir).join("AppData\\Roaming\\cursor-reset"),
+    };
+
+    #[cfg(target_os = "macos")]
+    let dir = PathBuf::from(&home_dir).join("Library/Application Support/com.cursor-reset.app");
+
+    let _ = std::fs::create_dir_all(&dir);
+    dir
+}
+
+pub fn get_config_path() -> PathBuf {
+    get_app_data_dir().join(CONFIG_FILE_NAME)
+}
+
+// 读取配置，文件不存在或解析失败时返回默认配置
+pub fn load_config() -> AppConfig {
+    if let Ok(mut file) = File::open(get_config_path()) {
+        let mut contents = String::new();
+        if file.read_to_string(&mut contents).is_ok() {
+            if let Ok(config) = serde_json::from_str::<AppConfig>(&contents) {
+                return config;
+            }
+        }
+    }
+    AppConfig::default()
+}
+
+pub fn save_config(config: &AppConfig) -> bool {
+    let json_str = match serde_json::to_string_pretty(config) {
+        Ok(json_str) => json_str,
+        Err(_) => return false,
+    };
+    match File::create(get_config_path()) {
+        Ok(mut file) => file.write_all(json_str.as_bytes()).is_ok(),
+        Err(_) => false,
+    }
+}
@@ -0,0 +1,177 @@
+use crate::model::crash_model::{CrashDump, CrashSummary};
+use crate::model::editor_model::EditorProfile;
+use crate::utils::config_util::get_app_data_dir;
+use crate::utils::editor_util::get_data_dir;
+use crate::utils::i18n_util::{t, t_args};
+use crate::utils::time_util::{file_stamp, format_utc, modified_secs, now_secs};
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use zip::write::SimpleFileOptions;
+use zip::ZipWriter;
+
+// Electron 默认把崩溃转储写到用户数据目录下的 Crashpad
+pub fn get_crash_dir(profile: &EditorProfile) -> Result<PathBuf, String> {
+    get_data_dir(profile)
+        .map(|dir| dir.join("Crashpad"))
+        .ok_or_else(|| t("error.data_dir_not_found"))
+}
+
+// Crashpad 注解以 MINIDUMP_UTF8_STRING（u32 长度 + 内容 + \0）保存，
+// 键 "ver" 后面紧跟的字符串就是 Electron 写入的应用版本
+fn read_version(path: &Path) -> String {
+    let bytes = match std::fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(_) => return "".to_string(),
+    };
+    let key = b"\x03\x00\x00\x00ver\x00";
+    let mut from = 0;
+    while let Some(pos) = bytes[from..]
+        .windows(key.len())
+        .position(|window| window == key)
+    {
+        let start = from + pos + key.len();
+        // 下一个字符串按 4 字节对齐
+        let aligned = (start + 3) & !3;
+        for offset in [start, aligned] {
+            if let Some(version) = read_utf8_string(&bytes, offset) {
+                if is_version(&version) {
+                    return version;
+                }
+            }
+        }
+        from = start;
+    }
+    "".to_string()
+}
+
+fn read_utf8_string(bytes: &[u8], offset: usize) -> Option<String> {
+    let len_bytes: [u8; 4] = bytes.get(offset..offset + 4)?.try_into().ok()?;
+    let len = u32::from_le_bytes(len_bytes) as usize;
+    if len == 0 || len > 64 {
+        return None;
+    }
+    let text = bytes.get(offset + 4..offset + 4 + len)?;
+    String::from_utf8(text.to_vec()).ok()
+}
+
+fn is_version(text: &str) -> bool {
+    text.contains('.')
+        && text
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-')
+}
+
+fn collect_dumps(root: &Path, dir: &Path, dumps: &mut Vec<CrashDump>) {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_dumps(root, &path, dumps);
+            continue;
+        }
+        if path.extension().is_none_or(|ext| ext != "dmp") {
+            continue;
+        }
+        let relative = path.strip_prefix(root).unwrap_or(&path);
+        let state = relative
+            .parent()
+            .and_then(|parent| parent.components().next())
+            .map(|part| part.as_os_str().to_string_lossy().to_string())
+            .unwrap_or_default();
+        dumps.push(CrashDump {
+            id: relative.to_string_lossy().replace('\\', "/"),
+            path: path.to_string_lossy().to_string(),
+            state,
+            created_at: format_utc(modified_secs(&path)),
+            size: std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0),
+            version: read_version(&path),
+        });
+    }
+}
+
+// 扫描崩溃转储，最新的在前
+pub fn list_crash_dumps(profile: &EditorProfile) -> Result<CrashSummary, String> {
+    let dir = get_crash_dir(profile)?;
+    let mut dumps = vec![];
+    collect_dumps(&dir, &dir, &mut dumps);
+    dumps.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+    Ok(CrashSummary {
+        dir: dir.to_string_lossy().to_string(),
+        count: dumps.len(),
+        total_size: dumps.iter().map(|dump| dump.size).sum(),
+        dumps,
+    })
+}
+
+// 崩溃转储数量，用于诊断包
+pub fn count_crash_dumps(profile: &EditorProfile) -> usize {
+    list_crash_dumps(profile)
+        .map(|summary| summary.count)
+        .unwrap_or(0)
+}
+
+// 按 id 选出转储，ids 为空时表示全部
+fn select_dumps(profile: &EditorProfile, ids: &[String]) -> Result<Vec<CrashDump>, String> {
+    let dumps = list_crash_dumps(profile)?.dumps;
+    if ids.is_empty() {
+        return Ok(dumps);
+    }
+    ids.iter()
+        .map(|id| {
+            dumps
+                .iter()
+                .find(|dump| &dump.id == id)
+                .cloned()
+                .ok_or_else(|| t_args("error.crash_dump_not_found", &[id]))
+        })
+        .collect()
+}
+
+pub fn default_archive_path(profile: &EditorProfile) -> PathBuf {
+    let dir = get_app_data_dir().join("exports");
+    let _ = std::fs::create_dir_all(&dir);
+    dir.join(format!(
+        "crashes-{}-{}.zip",
+        profile.id,
+        file_stamp(now_secs())
+    ))
+}
+
+// 将转储打包为 zip，返回文件路径
+pub fn archive_crash_dumps(
+    profile: &EditorProfile,
+    ids: &[String],
+    target: &Path,
+) -> Result<String, String> {
+    let dumps = select_dumps(profile, ids)?;
+    if let Some(parent) = target.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let file = File::create(target).map_err(|e| e.to_string())?;
+    let mut zip = ZipWriter::new(file);
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+    for dump in &dumps {
+        let mut source = File::open(&dump.path).map_err(|e| e.to_string())?;
+        zip.start_file(dump.id.as_str(), options)
+            .map_err(|e| e.to_string())?;
+        std::io::copy(&mut source, &mut zip).map_err(|e| e.to_string())?;
+    }
+    let json_str = serde_json::to_string_pretty(&dumps).map_err(|e| e.to_string())?;
+    zip.start_file("crashes.json", options)
+        .map_err(|e| e.to_string())?;
+    std::io::Write::write_all(&mut zip, json_str.as_bytes()).map_err(|e| e.to_string())?;
+    zip.finish().map_err(|e| e.to_string())?;
+    Ok(target.to_string_lossy().to_string())
+}
+
+// 删除转储，返回删除的数量
+pub fn delete_crash_dumps(profile: &EditorProfile, ids: &[String]) -> Result<usize, String> {
+    let dumps = select_dumps(profile, ids)?;
+    for dump in &dumps {
+        std::fs::remove_file(&dump.path).map_err(|e| e.to_string())?;
+    }
+    Ok(dumps.len())
+}
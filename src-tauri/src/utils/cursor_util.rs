@@ -1,18 +1,6 @@
-#[cfg(target_os = "windows")]
-const PACKAGE_JSON_ARR: [&str; 3] = [
-    "AppData\\Roaming\\Cursor\\User\\globalStorage\\storage.json",
-    "AppData\\Programs\\cursor\\resources\\app\\package.json",
-    "AppData\\cursor\\resources\\app\\package.json",
-];
-
-#[cfg(target_os = "macos")]
-const PACKAGE_JSON_ARR: [&str; 3] = [
-    "Library/Application Support/Cursor/User/globalStorage/storage.json",
-    "Applications/Cursor.app/Contents/Resources/storage.json",
-    "Library/Application Support/Cursor/storage.json",
-];
-
 use crate::model::cursor_model::CursorDeviceInfo;
+use crate::model::editor_model::{EditorProfile, TelemetryKeys};
+use crate::utils::editor_util::resolve_home_path;
 use rand::Rng;
 #[cfg(target_os = "macos")]
 use regex::Regex;
@@ -24,7 +12,7 @@ use std::path::PathBuf;
 use uuid::Uuid;
 
 // 返回当前用户根目录
-fn get_user_home_dir() -> String {
+pub fn get_user_home_dir() -> String {
     #[cfg(target_os = "windows")]
     {
         match env::var_os("USERPROFILE") {
@@ -42,19 +30,22 @@ fn get_user_home_dir() -> String {
     }
 }
 
-pub fn get_package_path() -> String {
-    let home_dir = get_user_home_dir();
-    if home_dir.is_empty() {
-        return "".to_string();
+// 返回编辑器所有候选的 storage.json 完整路径
+pub fn get_package_path_candidates(profile: &EditorProfile) -> Vec<String> {
+    if get_user_home_dir().is_empty() {
+        return vec![];
     }
 
-    for item in PACKAGE_JSON_ARR {
-        #[cfg(target_os = "windows")]
-        let full_path = format!("{}\\{}", home_dir, item);
-
-        #[cfg(target_os = "macos")]
-        let full_path = format!("{}/{}", home_dir, item);
+    profile
+        .storage_files
+        .current()
+        .iter()
+        .map(|item| resolve_home_path(item).to_string_lossy().to_string())
+        .collect()
+}
 
+pub fn get_package_path(profile: &EditorProfile) -> String {
+    for full_path in get_package_path_candidates(profile) {
         if std::path::Path::new(&full_path).exists() {
             return full_path;
         }
@@ -62,7 +53,7 @@ pub fn get_package_path() -> String {
     "".to_string()
 }
 
-pub fn read_device_info(path: String) -> CursorDeviceInfo {
+pub fn read_device_info(path: String, keys: &TelemetryKeys) -> CursorDeviceInfo {
     // 默认值
     let mut mac_machine_id = "".to_string();
     let mut machine_id = "".to_string();
@@ -79,29 +70,19 @@ pub fn read_device_info(path: String) -> CursorDeviceInfo {
                 // 作为 Map 迭代所有键值对
                 if let Some(obj) = json.as_object() {
                     for (key, value) in obj {
-                        match key.as_str() {
-                            "telemetry.macMachineId" => {
-                                if let Some(str_val) = value.as_str() {
-                                    mac_machine_id = str_val.to_string();
-                                }
-                            }
-                            "telemetry.machineId" => {
-                                if let Some(str_val) = value.as_str() {
-                                    machine_id = str_val.to_string();
-                                }
-                            }
-                            "telemetry.sqmId" => {
-                                if let Some(str_val) = value.as_str() {
-                                    sqm_id = str_val.to_string();
-                                }
-                            }
-                            "telemetry.devDeviceId" => {
-                                if let Some(str_val) = value.as_str() {
-                                    dev_device_id = str_val.to_string();
-                                }
-                            }
-                            _ => {} // 忽略其他键
-                        }
+                        let str_val = match value.as_str() {
+                            Some(str_val) => str_val.to_string(),
+                            None => continue,
+                        };
+                        if *key == keys.mac_machine_id {
+                            mac_machine_id = str_val;
+                        } else if *key == keys.machine_id {
+                            machine_id = str_val;
+                        } else if *key == keys.sqm_id {
+                            sqm_id = str_val;
+                        } else if *key == keys.dev_device_id {
+                            dev_device_id = str_val;
+                        } // 忽略其他键
                     }
                 }
             }
@@ -123,7 +104,8 @@ fn generate_hex_str(size: i32) -> String {
     result
 }
 
-pub fn reset_device_info(path: String) -> CursorDeviceInfo {
+pub fn reset_device_info(path: String, profile: &EditorProfile) -> CursorDeviceInfo {
+    let keys = &profile.telemetry_keys;
     let mac_machine_id = Uuid::new_v4().to_string();
     let machine_id = generate_hex_str(64);
     let sqm_id = format!("{{{}}}", Uuid::new_v4().to_string().to_uppercase());
@@ -149,19 +131,19 @@ pub fn reset_device_info(path: String) -> CursorDeviceInfo {
 
     // 更新需要修改的键值
     json_map.insert(
-        "telemetry.macMachineId".to_string(),
+        keys.mac_machine_id.clone(),
         serde_json::Value::String(info.mac_machine_id.clone()),
     );
     json_map.insert(
-        "telemetry.machineId".to_string(),
+        keys.machine_id.clone(),
         serde_json::Value::String(info.machine_id.clone()),
     );
     json_map.insert(
-        "telemetry.sqmId".to_string(),
+        keys.sqm_id.clone(),
         serde_json::Value::String(info.sqm_id.clone()),
     );
     json_map.insert(
-        "telemetry.devDeviceId".to_string(),
+        keys.dev_device_id.clone(),
         serde_json::Value::String(info.dev_device_id.clone()),
     );
 
@@ -177,8 +159,13 @@ pub fn reset_device_info(path: String) -> CursorDeviceInfo {
     // 更新main.js
     #[cfg(target_os = "macos")]
     {
-        let main_js_path = "/Applications/Cursor.app/Contents/Resources/app/out/main.js".to_string();
-        update_main_js(main_js_path);
+        for main_js_path in profile.main_js.current() {
+            update_main_js(
+                resolve_home_path(main_js_path)
+                    .to_string_lossy()
+                    .to_string(),
+            );
+        }
     }
     info
 }
@@ -0,0 +1,120 @@
+use crate::model::cursor_model::MaskMode;
+use crate::model::diagnostics_model::{DiagnosticsSummary, PathCheck};
+use crate::model::editor_model::EditorProfile;
+use crate::utils::config_util::{get_app_data_dir, load_config};
+use crate::utils::crash_util::count_crash_dumps;
+use crate::utils::cursor_util::{get_package_path, get_package_path_candidates, read_device_info};
+use crate::utils::install_util::find_install_info;
+use crate::utils::log_util::read_recent_logs;
+use crate::utils::process_util::is_running;
+use crate::utils::redact_util::{redact_install_info, redact_path};
+use crate::utils::time_util::{file_stamp, format_utc, now_secs};
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use zip::write::SimpleFileOptions;
+use zip::ZipWriter;
+
+const RECENT_LOG_LINES: usize = 500;
+
+// 检查单个候选路径是否存在以及读写权限
+pub fn check_path(path: &str) -> PathCheck {
+    let metadata = std::fs::metadata(path);
+    let (exists, is_file, readonly, size) = match &metadata {
+        Ok(meta) => (
+            true,
+            meta.is_file(),
+            meta.permissions().readonly(),
+            meta.len(),
+        ),
+        Err(_) => (false, false, false, 0),
+    };
+    // 以写方式打开（不截断）来确认当前用户是否真的可写
+    let writable = is_file && std::fs::OpenOptions::new().write(true).open(path).is_ok();
+    PathCheck {
+        path: redact_path(path),
+        exists,
+        is_file,
+        readonly,
+        writable,
+        size,
+    }
+}
+
+pub fn check_package_paths(profile: &EditorProfile) -> Vec<PathCheck> {
+    get_package_path_candidates(profile)
+        .iter()
+        .map(|path| check_path(path))
+        .collect()
+}
+
+// 默认的诊断包保存位置
+pub fn default_export_path() -> PathBuf {
+    let dir = get_app_data_dir().join("diagnostics");
+    let _ = std::fs::create_dir_all(&dir);
+    dir.join(format!(
+        "cursor-reset-diagnostics-{}.zip",
+        file_stamp(now_secs())
+    ))
+}
+
+fn add_json<T: serde::Serialize>(
+    zip: &mut ZipWriter<File>,
+    name: &str,
+    value: &T,
+) -> Result<(), String> {
+    let json_str = serde_json::to_string_pretty(value).map_err(|e| e.to_string())?;
+    add_text(zip, name, &json_str)
+}
+
+fn add_text(zip: &mut ZipWriter<File>, name: &str, text: &str) -> Result<(), String> {
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+    zip.start_file(name, options).map_err(|e| e.to_string())?;
+    zip.write_all(text.as_bytes()).map_err(|e| e.to_string())
+}
+
+// 生成诊断包：安装信息（脱敏）、机器码（遮盖）、路径检查、本工具配置和最近日志
+pub fn export_diagnostics(target: &Path, profile: &EditorProfile) -> Result<String, String> {
+    if let Some(parent) = target.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let file = File::create(target).map_err(|e| e.to_string())?;
+    let mut zip = ZipWriter::new(file);
+
+    let package_path = get_package_path(profile);
+    let summary = DiagnosticsSummary {
+        editor: profile.id.clone(),
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        os: std::env::consts::OS.to_string(),
+        arch: std::env::consts::ARCH.to_string(),
+        generated_at: format_utc(now_secs()),
+        cursor_running: is_running(profile),
+        package_path_found: !package_path.is_empty(),
+        crash_count: count_crash_dumps(profile),
+    };
+    add_json(&mut zip, "summary.json", &summary)?;
+    add_json(
+        &mut zip,
+        "install_info.json",
+        &redact_install_info(&find_install_info(profile)),
+    )?;
+    if !package_path.is_empty() {
+        let device_info =
+            read_device_info(package_path, &profile.telemetry_keys).masked(MaskMode::Partial);
+        add_json(&mut zip, "device_info.json", &device_info)?;
+    }
+    add_json(
+        &mut zip,
+        "package_paths.json",
+        &check_package_paths(profile),
+    )?;
+    add_json(&mut zip, "config.json", &load_config())?;
+    add_text(
+        &mut zip,
+        "logs/cursor-reset.log",
+        &read_recent_logs(RECENT_LOG_LINES).join("\n"),
+    )?;
+
+    zip.finish().map_err(|e| e.to_string())?;
+    Ok(target.to_string_lossy().to_string())
+}
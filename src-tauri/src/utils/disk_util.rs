@@ -0,0 +1,80 @@
+use crate::model::disk_model::{CacheCategory, CategoryUsage, CleanResult, DiskUsage};
+use crate::model::editor_model::EditorProfile;
+use crate::utils::editor_util::get_data_dir;
+use crate::utils::fs_util::dir_size;
+use crate::utils::i18n_util::t;
+use std::path::{Path, PathBuf};
+
+fn category_path(data_dir: &Path, category: CacheCategory) -> PathBuf {
+    category
+        .relative_path()
+        .split('/')
+        .fold(data_dir.to_path_buf(), |path, part| path.join(part))
+}
+
+// 统计数据目录及各缓存类别的占用
+pub fn disk_usage(profile: &EditorProfile) -> Result<DiskUsage, String> {
+    let data_dir = get_data_dir(profile).ok_or_else(|| t("error.data_dir_not_found"))?;
+    let categories = CacheCategory::ALL
+        .into_iter()
+        .map(|category| {
+            let path = category_path(&data_dir, category);
+            CategoryUsage {
+                category,
+                exists: path.exists(),
+                size: dir_size(&path),
+                path: path.to_string_lossy().to_string(),
+            }
+        })
+        .collect();
+    Ok(DiskUsage {
+        total: dir_size(&data_dir),
+        data_dir: data_dir.to_string_lossy().to_string(),
+        categories,
+    })
+}
+
+// 删除目录下的全部内容（保留目录本身），返回释放的字节数，删除失败的路径记入 failed
+fn clear_dir(dir: &Path, failed: &mut Vec<String>) -> u64 {
+    let mut freed = 0;
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return 0,
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let size = dir_size(&path);
+        let removed = if path.is_dir() {
+            std::fs::remove_dir_all(&path)
+        } else {
+            std::fs::remove_file(&path)
+        };
+        match removed {
+            Ok(_) => freed += size,
+            // 部分删除时按剩余大小估算释放量
+            Err(_) => {
+                freed += size.saturating_sub(dir_size(&path));
+                failed.push(path.to_string_lossy().to_string());
+            }
+        }
+    }
+    freed
+}
+
+// 清理选中的缓存类别，调用方需确保编辑器未运行
+pub fn clean(profile: &EditorProfile, categories: &[CacheCategory]) -> Result<CleanResult, String> {
+    let data_dir = get_data_dir(profile).ok_or_else(|| t("error.data_dir_not_found"))?;
+    let mut result = CleanResult::default();
+    for category in CacheCategory::ALL {
+        if !categories.contains(&category) {
+            continue;
+        }
+        let path = category_path(&data_dir, category);
+        if !path.exists() {
+            continue;
+        }
+        result.freed += clear_dir(&path, &mut result.failed);
+        result.cleaned.push(category);
+    }
+    Ok(result)
+}
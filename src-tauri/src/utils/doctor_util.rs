@@ -0,0 +1,221 @@
+use crate::model::cursor_model::CursorDeviceInfo;
+use crate::model::doctor_model::{CheckStatus, DoctorCheck};
+use crate::model::editor_model::EditorProfile;
+use crate::utils::cursor_util::{get_package_path, read_device_info};
+use crate::utils::diagnostics_util::check_path;
+use crate::utils::editor_util::get_data_dir;
+use crate::utils::i18n_util::{t, t_args};
+use crate::utils::install_util::{find_install_info, get_product_json_path};
+use crate::utils::jsonc_util::read_file;
+use crate::utils::process_util::find_stale_processes;
+use crate::utils::redact_util::{redact_path, redact_text};
+use base64::engine::general_purpose::STANDARD_NO_PAD;
+use base64::Engine;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::path::Path;
+
+// 可用空间低于该值时警告
+const DISK_WARN_BYTES: u64 = 1024 * 1024 * 1024;
+// 可用空间低于该值时视为失败
+const DISK_FAIL_BYTES: u64 = 200 * 1024 * 1024;
+
+fn check(id: &str, status: CheckStatus, detail: String) -> DoctorCheck {
+    DoctorCheck {
+        id: id.to_string(),
+        title: t(&format!("doctor.{}", id)),
+        status,
+        detail,
+        remedy: if status == CheckStatus::Pass {
+            "".to_string()
+        } else {
+            t(&format!("doctor.{}.remedy", id))
+        },
+    }
+}
+
+fn is_hex(value: &str, len: usize) -> bool {
+    value.len() == len && value.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+fn is_uuid(value: &str) -> bool {
+    let parts: Vec<&str> = value.split('-').collect();
+    parts.len() == 5
+        && parts
+            .iter()
+            .zip([8, 4, 4, 4, 12])
+            .all(|(part, len)| is_hex(part, len))
+}
+
+// 不符合格式的机器码字段名
+fn malformed_identifiers(info: &CursorDeviceInfo) -> Vec<&'static str> {
+    let mut malformed = vec![];
+    // 编辑器生成的是 64 位十六进制，本工具重置后为 UUID，两种都接受
+    if !is_hex(&info.mac_machine_id, 64) && !is_uuid(&info.mac_machine_id) {
+        malformed.push("macMachineId");
+    }
+    if !is_hex(&info.machine_id, 64) {
+        malformed.push("machineId");
+    }
+    let sqm_inner = info
+        .sqm_id
+        .strip_prefix('{')
+        .and_then(|id| id.strip_suffix('}'));
+    if !info.sqm_id.is_empty() && !sqm_inner.is_some_and(is_uuid) {
+        malformed.push("sqmId");
+    }
+    if !is_uuid(&info.dev_device_id) {
+        malformed.push("devDeviceId");
+    }
+    malformed
+}
+
+// product.json 中 checksums 记录的文件（相对 out 目录）与实际内容不一致的列表
+fn modified_app_files(product_json: &Path) -> Result<Vec<String>, String> {
+    let product = read_file(product_json)?;
+    let checksums = match product.get("checksums").and_then(|v| v.as_object()) {
+        Some(checksums) => checksums,
+        None => return Ok(vec![]),
+    };
+    let out_dir = product_json
+        .parent()
+        .map(|dir| dir.join("out"))
+        .unwrap_or_default();
+    let mut modified = vec![];
+    for (file, expected) in checksums {
+        let path = file
+            .split('/')
+            .fold(out_dir.clone(), |path, part| path.join(part));
+        let actual = std::fs::read(&path)
+            .map(|bytes| STANDARD_NO_PAD.encode(Sha256::digest(&bytes)))
+            .unwrap_or_default();
+        if Some(actual.as_str()) != expected.as_str() {
+            modified.push(file.clone());
+        }
+    }
+    Ok(modified)
+}
+
+// 依次执行全部检查
+pub fn run_checks(profile: &EditorProfile) -> Vec<DoctorCheck> {
+    let mut checks = vec![];
+
+    let install_info = find_install_info(profile);
+    checks.push(if install_info.install_path.is_empty() {
+        check("install_found", CheckStatus::Fail, "".to_string())
+    } else {
+        check(
+            "install_found",
+            CheckStatus::Pass,
+            format!(
+                "{} {}",
+                redact_path(&install_info.install_path),
+                install_info.install_version
+            ),
+        )
+    });
+
+    let data_dir = get_data_dir(profile);
+    checks.push(match &data_dir {
+        Some(dir) => check(
+            "data_dir_found",
+            CheckStatus::Pass,
+            redact_path(&dir.to_string_lossy()),
+        ),
+        None => check("data_dir_found", CheckStatus::Fail, "".to_string()),
+    });
+
+    let package_path = get_package_path(profile);
+    let storage_ok = match read_file(Path::new(&package_path)) {
+        _ if package_path.is_empty() => {
+            checks.push(check("storage_parses", CheckStatus::Fail, "".to_string()));
+            false
+        }
+        Ok(Value::Object(_)) => {
+            checks.push(check(
+                "storage_parses",
+                CheckStatus::Pass,
+                redact_path(&package_path),
+            ));
+            true
+        }
+        Ok(_) => {
+            checks.push(check(
+                "storage_parses",
+                CheckStatus::Fail,
+                redact_path(&package_path),
+            ));
+            false
+        }
+        Err(e) => {
+            checks.push(check("storage_parses", CheckStatus::Fail, e));
+            false
+        }
+    };
+
+    if storage_ok {
+        let info = read_device_info(package_path.clone(), &profile.telemetry_keys);
+        let malformed = malformed_identifiers(&info);
+        checks.push(if malformed.is_empty() {
+            check("identifiers_valid", CheckStatus::Pass, "".to_string())
+        } else {
+            check("identifiers_valid", CheckStatus::Warn, malformed.join(", "))
+        });
+
+        let path_check = check_path(&package_path);
+        checks.push(if path_check.writable && !path_check.readonly {
+            check("storage_writable", CheckStatus::Pass, path_check.path)
+        } else {
+            check("storage_writable", CheckStatus::Warn, path_check.path)
+        });
+    }
+
+    match get_product_json_path(profile, &install_info).map(|path| modified_app_files(&path)) {
+        Some(Ok(modified)) if modified.is_empty() => {
+            checks.push(check("app_files_intact", CheckStatus::Pass, "".to_string()))
+        }
+        Some(Ok(modified)) => checks.push(check(
+            "app_files_intact",
+            CheckStatus::Warn,
+            modified.join(", "),
+        )),
+        Some(Err(e)) => checks.push(check("app_files_intact", CheckStatus::Warn, e)),
+        None => checks.push(check("app_files_intact", CheckStatus::Warn, "".to_string())),
+    }
+
+    if let Some(dir) = &data_dir {
+        match fs2::available_space(dir) {
+            Ok(free) => {
+                let status = if free < DISK_FAIL_BYTES {
+                    CheckStatus::Fail
+                } else if free < DISK_WARN_BYTES {
+                    CheckStatus::Warn
+                } else {
+                    CheckStatus::Pass
+                };
+                checks.push(check(
+                    "disk_space",
+                    status,
+                    t_args(
+                        "doctor.disk_space.free",
+                        &[&(free / 1024 / 1024).to_string()],
+                    ),
+                ));
+            }
+            Err(e) => checks.push(check("disk_space", CheckStatus::Warn, e.to_string())),
+        }
+    }
+
+    let stale = find_stale_processes(profile);
+    checks.push(if stale.is_empty() {
+        check("no_stale_processes", CheckStatus::Pass, "".to_string())
+    } else {
+        check(
+            "no_stale_processes",
+            CheckStatus::Warn,
+            redact_text(&stale.join(", ")),
+        )
+    });
+
+    checks
+}
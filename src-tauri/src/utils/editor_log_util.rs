@@ -0,0 +1,348 @@
+use crate::model::editor_log_model::{LogFileInfo, LogFilter, LogLine, LogSession};
+use crate::model::editor_model::EditorProfile;
+use crate::utils::editor_util::get_data_dir;
+use crate::utils::fs_util::dir_size;
+use crate::utils::i18n_util::{t, t_args};
+use crate::utils::time_util::{format_utc, modified_secs};
+use std::collections::HashMap;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+// 未指定 limit 时最多返回的条数
+const DEFAULT_LIMIT: usize = 2000;
+// tail 轮询间隔
+const TAIL_INTERVAL_MILLIS: u64 = 1000;
+
+// 编辑器的 logs 目录
+pub fn get_logs_dir(profile: &EditorProfile) -> Result<PathBuf, String> {
+    get_data_dir(profile)
+        .map(|dir| dir.join("logs"))
+        .ok_or_else(|| t("error.data_dir_not_found"))
+}
+
+// 20250101T080000 → 2025-01-01 08:00:00，格式不符时原样返回
+fn session_time(id: &str) -> String {
+    let bytes = id.as_bytes();
+    if bytes.len() >= 15
+        && bytes[8] == b'T'
+        && bytes.iter().take(15).filter(|b| b.is_ascii_digit()).count() == 14
+    {
+        format!(
+            "{}-{}-{} {}:{}:{}",
+            &id[0..4],
+            &id[4..6],
+            &id[6..8],
+            &id[9..11],
+            &id[11..13],
+            &id[13..15]
+        )
+    } else {
+        id.to_string()
+    }
+}
+
+// 递归收集会话目录下的 .log 文件
+fn collect_log_files(session_dir: &Path, dir: &Path, files: &mut Vec<LogFileInfo>) {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_log_files(session_dir, &path, files);
+            continue;
+        }
+        if path.extension().is_none_or(|ext| ext != "log") {
+            continue;
+        }
+        let relative = path.strip_prefix(session_dir).unwrap_or(&path);
+        let window = relative
+            .components()
+            .next()
+            .map(|part| part.as_os_str().to_string_lossy().to_string())
+            .filter(|part| part.starts_with("window"))
+            .unwrap_or_default();
+        files.push(LogFileInfo {
+            file: relative.to_string_lossy().replace('\\', "/"),
+            channel: path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            window,
+            size: std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0),
+            modified_at: format_utc(modified_secs(&path)),
+        });
+    }
+}
+
+fn read_session(dir: &Path) -> LogSession {
+    let id = dir
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let mut files = vec![];
+    collect_log_files(dir, dir, &mut files);
+    files.sort_by(|a, b| a.file.cmp(&b.file));
+    let mut windows: Vec<String> = files
+        .iter()
+        .filter(|file| !file.window.is_empty())
+        .map(|file| file.window.clone())
+        .collect();
+    windows.sort();
+    windows.dedup();
+    LogSession {
+        started_at: session_time(&id),
+        id,
+        path: dir.to_string_lossy().to_string(),
+        size: dir_size(dir),
+        windows,
+        files,
+    }
+}
+
+// 列出全部日志会话，最新的在前
+pub fn list_sessions(profile: &EditorProfile) -> Result<Vec<LogSession>, String> {
+    let logs_dir = get_logs_dir(profile)?;
+    let mut sessions = vec![];
+    if let Ok(entries) = std::fs::read_dir(&logs_dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                sessions.push(read_session(&path));
+            }
+        }
+    }
+    sessions.sort_by(|a, b| b.id.cmp(&a.id));
+    Ok(sessions)
+}
+
+fn find_session(profile: &EditorProfile, id: &str) -> Result<LogSession, String> {
+    let sessions = list_sessions(profile)?;
+    let session = if id.is_empty() {
+        sessions.into_iter().next()
+    } else {
+        sessions.into_iter().find(|session| session.id == id)
+    };
+    session.ok_or_else(|| t_args("error.log_session_not_found", &[id]))
+}
+
+// 解析一行日志头：2025-01-01 08:00:00.123 [info] message，返回 (时间, 级别, 内容)
+pub fn parse_line(line: &str) -> Option<(String, String, String)> {
+    let bytes = line.as_bytes();
+    if bytes.len() < 19
+        || !bytes[0..4].iter().all(|b| b.is_ascii_digit())
+        || bytes[4] != b'-'
+        || bytes[10] != b' '
+        || bytes[13] != b':'
+    {
+        return None;
+    }
+    let level_start = line.find(" [")?;
+    // 时间戳只包含日期和时间两段
+    if line[11..level_start].contains(' ') {
+        return None;
+    }
+    let level_end = level_start + line[level_start..].find(']')?;
+    let timestamp = line[..level_start].to_string();
+    let level = line[level_start + 2..level_end].to_lowercase();
+    let message = line[level_end + 1..].trim_start().to_string();
+    Some((timestamp, level, message))
+}
+
+// 将文本解析为日志条目，没有日志头的行追加到上一条
+fn parse_text(
+    text: &str,
+    session: &str,
+    file: &LogFileInfo,
+    first_line: usize,
+    lines: &mut Vec<LogLine>,
+) {
+    let start = lines.len();
+    for (index, raw) in text.lines().enumerate() {
+        match parse_line(raw) {
+            Some((timestamp, level, message)) => lines.push(LogLine {
+                session: session.to_string(),
+                file: file.file.clone(),
+                channel: file.channel.clone(),
+                line_number: first_line + index,
+                timestamp,
+                level,
+                message,
+            }),
+            None if lines.len() > start => {
+                if let Some(last) = lines.last_mut() {
+                    last.message.push('\n');
+                    last.message.push_str(raw);
+                }
+            }
+            None if !raw.trim().is_empty() => lines.push(LogLine {
+                session: session.to_string(),
+                file: file.file.clone(),
+                channel: file.channel.clone(),
+                line_number: first_line + index,
+                timestamp: "".to_string(),
+                level: "".to_string(),
+                message: raw.to_string(),
+            }),
+            None => {}
+        }
+    }
+}
+
+fn matches(filter: &LogFilter, line: &LogLine) -> bool {
+    (filter.levels.is_empty()
+        || filter
+            .levels
+            .iter()
+            .any(|level| level.eq_ignore_ascii_case(&line.level)))
+        && (filter.query.is_empty()
+            || line
+                .message
+                .to_lowercase()
+                .contains(&filter.query.to_lowercase()))
+}
+
+fn file_matches(filter: &LogFilter, file: &LogFileInfo) -> bool {
+    (filter.window.is_empty() || file.window == filter.window)
+        && (filter.channels.is_empty() || filter.channels.contains(&file.channel))
+}
+
+// 读取并过滤日志，按时间排序，返回最新的 limit 条
+pub fn read_logs(profile: &EditorProfile, filter: &LogFilter) -> Result<Vec<LogLine>, String> {
+    let session = find_session(profile, &filter.session)?;
+    let mut lines = vec![];
+    for file in session
+        .files
+        .iter()
+        .filter(|file| file_matches(filter, file))
+    {
+        let path = Path::new(&session.path).join(&file.file);
+        let bytes = std::fs::read(&path).unwrap_or_default();
+        let mut parsed = vec![];
+        parse_text(
+            &String::from_utf8_lossy(&bytes),
+            &session.id,
+            file,
+            1,
+            &mut parsed,
+        );
+        lines.extend(parsed.into_iter().filter(|line| matches(filter, line)));
+    }
+    lines.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+    let limit = if filter.limit == 0 {
+        DEFAULT_LIMIT
+    } else {
+        filter.limit
+    };
+    if lines.len() > limit {
+        lines.drain(..lines.len() - limit);
+    }
+    Ok(lines)
+}
+
+// 在全部日志中查找第一行包含指定文本的原始内容
+pub fn find_first_line(profile: &EditorProfile, needle: &str) -> Option<String> {
+    for session in list_sessions(profile).ok()? {
+        for file in &session.files {
+            let path = Path::new(&session.path).join(&file.file);
+            let bytes = match std::fs::read(&path) {
+                Ok(bytes) => bytes,
+                Err(_) => continue,
+            };
+            if let Some(line) = String::from_utf8_lossy(&bytes)
+                .lines()
+                .find(|line| line.contains(needle))
+            {
+                return Some(line.to_string());
+            }
+        }
+    }
+    None
+}
+
+// 已读取到的位置：(字节偏移, 已读行数)
+type TailOffsets = HashMap<String, (u64, usize)>;
+
+// 读取文件自上次偏移以来新增的完整行
+fn read_appended(
+    path: &Path,
+    session: &str,
+    file: &LogFileInfo,
+    offsets: &mut TailOffsets,
+) -> Vec<LogLine> {
+    let (offset, line_count) = offsets.get(&file.file).copied().unwrap_or((0, 0));
+    let mut handle = match std::fs::File::open(path) {
+        Ok(handle) => handle,
+        Err(_) => return vec![],
+    };
+    let len = handle.metadata().map(|m| m.len()).unwrap_or(0);
+    // 文件被截断或轮转后从头读取
+    let (offset, line_count) = if len < offset {
+        (0, 0)
+    } else {
+        (offset, line_count)
+    };
+    if len == offset || handle.seek(SeekFrom::Start(offset)).is_err() {
+        return vec![];
+    }
+    let mut buffer = vec![];
+    if handle.read_to_end(&mut buffer).is_err() {
+        return vec![];
+    }
+    // 只处理到最后一个换行，未写完的行留到下一轮
+    let complete = match buffer.iter().rposition(|b| *b == b'\n') {
+        Some(pos) => pos + 1,
+        None => return vec![],
+    };
+    let text = String::from_utf8_lossy(&buffer[..complete]).to_string();
+    let mut lines = vec![];
+    parse_text(&text, session, file, line_count + 1, &mut lines);
+    offsets.insert(
+        file.file.clone(),
+        (offset + complete as u64, line_count + text.lines().count()),
+    );
+    lines
+}
+
+// 持续读取最新会话中新写入的日志，直到 stop 被置位；每轮有新内容时调用 on_lines
+pub fn tail(
+    profile: &EditorProfile,
+    filter: &LogFilter,
+    stop: Arc<AtomicBool>,
+    mut on_lines: impl FnMut(Vec<LogLine>),
+) {
+    let mut offsets = TailOffsets::new();
+    let mut current = String::new();
+    let mut first_round = true;
+    while !stop.load(Ordering::Relaxed) {
+        if let Ok(session) = find_session(profile, &filter.session) {
+            // 编辑器重启后切换到新的会话
+            if session.id != current {
+                current = session.id.clone();
+                offsets.clear();
+            }
+            let mut lines = vec![];
+            for file in session
+                .files
+                .iter()
+                .filter(|file| file_matches(filter, file))
+            {
+                let path = Path::new(&session.path).join(&file.file);
+                let appended = read_appended(&path, &session.id, file, &mut offsets);
+                // 第一轮只记录位置，不推送已有内容
+                if !first_round {
+                    lines.extend(appended.into_iter().filter(|line| matches(filter, line)));
+                }
+            }
+            first_round = false;
+            if !lines.is_empty() {
+                lines.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+                on_lines(lines);
+            }
+        }
+        std::thread::sleep(std::time::Duration::from_millis(TAIL_INTERVAL_MILLIS));
+    }
+}
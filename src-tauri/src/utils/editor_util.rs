@@ -0,0 +1,63 @@
+use crate::model::editor_model::EditorProfile;
+use crate::utils::config_util::get_app_data_dir;
+use crate::utils::cursor_util::get_user_home_dir;
+use std::path::{Path, PathBuf};
+
+// 未指定编辑器时使用的默认编辑器
+pub const DEFAULT_EDITOR: &str = "cursor";
+
+// 内置的编辑器清单
+const EMBEDDED_MANIFEST: &str = include_str!("editors.json");
+// 用户可在应用数据目录放置同名文件，覆盖或追加编辑器描述
+const USER_MANIFEST_NAME: &str = "editors.json";
+
+fn parse_manifest(contents: &str) -> Vec<EditorProfile> {
+    serde_json::from_str::<Vec<EditorProfile>>(contents).unwrap_or_default()
+}
+
+// 读取全部编辑器描述：内置清单 + 用户清单（相同 id 以用户清单为准）
+pub fn load_editor_profiles() -> Vec<EditorProfile> {
+    let mut profiles = parse_manifest(EMBEDDED_MANIFEST);
+    let user_manifest = get_app_data_dir().join(USER_MANIFEST_NAME);
+    if let Ok(contents) = std::fs::read_to_string(user_manifest) {
+        for profile in parse_manifest(&contents) {
+            match profiles.iter_mut().find(|p| p.id == profile.id) {
+                Some(existing) => *existing = profile,
+                None => profiles.push(profile),
+            }
+        }
+    }
+    profiles
+}
+
+// 按 id 查找编辑器，id 为空时使用默认编辑器
+pub fn get_editor_profile(id: Option<&str>) -> Option<EditorProfile> {
+    let id = match id {
+        Some(id) if !id.is_empty() => id,
+        _ => DEFAULT_EDITOR,
+    };
+    load_editor_profiles().into_iter().find(|p| p.id == id)
+}
+
+// 将清单中的路径转换为完整路径：绝对路径原样返回，相对路径拼接到用户根目录下
+pub fn resolve_home_path(path: &str) -> PathBuf {
+    if Path::new(path).is_absolute() {
+        return PathBuf::from(path);
+    }
+    PathBuf::from(get_user_home_dir()).join(path)
+}
+
+// 编辑器的用户数据目录（第一个存在的候选目录）
+pub fn get_data_dir(profile: &EditorProfile) -> Option<PathBuf> {
+    profile
+        .data_dirs
+        .current()
+        .iter()
+        .map(|dir| resolve_home_path(dir))
+        .find(|dir| dir.exists())
+}
+
+// 编辑器在用户根目录下的配置目录，例如 ~/.cursor
+pub fn get_home_config_dir(profile: &EditorProfile) -> PathBuf {
+    resolve_home_path(&profile.home_config_dir)
+}
@@ -0,0 +1,230 @@
+use crate::model::editor_model::EditorProfile;
+use crate::model::extension_model::{
+    ExtensionDiff, ExtensionInfo, ExtensionManifest, ManifestEntry, VersionMismatch,
+};
+use crate::utils::config_util::get_app_data_dir;
+use crate::utils::editor_util::get_home_config_dir;
+use crate::utils::fs_util::dir_size;
+use crate::utils::i18n_util::t_args;
+use crate::utils::state_db_util::{get_global_state_db_path, read_item};
+use crate::utils::time_util::{file_stamp, format_utc, now_secs};
+use serde_json::Value;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+#[cfg(target_os = "windows")]
+use crate::utils::process_util::CREATE_NO_WINDOW;
+#[cfg(target_os = "windows")]
+use std::os::windows::process::CommandExt;
+
+// 全局状态库中记录已禁用扩展的键
+const DISABLED_EXTENSIONS_KEY: &str = "extensionsIdentifiers/disabled";
+
+pub fn get_extensions_dir(profile: &EditorProfile) -> PathBuf {
+    get_home_config_dir(profile).join("extensions")
+}
+
+fn read_json(path: &Path) -> Option<Value> {
+    let contents = std::fs::read_to_string(path).ok()?;
+    serde_json::from_str::<Value>(&contents).ok()
+}
+
+// 已禁用扩展的 id 集合（小写）
+fn read_disabled_ids(profile: &EditorProfile) -> HashSet<String> {
+    let mut disabled = HashSet::new();
+    let db_path = match get_global_state_db_path(profile) {
+        Some(db_path) => db_path,
+        None => return disabled,
+    };
+    if let Ok(Some(value)) = read_item(&db_path, DISABLED_EXTENSIONS_KEY) {
+        if let Ok(Value::Array(items)) = serde_json::from_str::<Value>(&value) {
+            for item in items {
+                if let Some(id) = item.get("id").and_then(|id| id.as_str()) {
+                    disabled.insert(id.to_lowercase());
+                }
+            }
+        }
+    }
+    disabled
+}
+
+// 已标记为待删除的扩展目录名（.obsolete 文件）
+fn read_obsolete_dirs(extensions_dir: &Path) -> HashSet<String> {
+    match read_json(&extensions_dir.join(".obsolete")) {
+        Some(Value::Object(obj)) => obj.keys().cloned().collect(),
+        _ => HashSet::new(),
+    }
+}
+
+// 扩展目录列表：优先使用 extensions.json，没有时扫描子目录
+fn list_extension_dirs(extensions_dir: &Path) -> Vec<PathBuf> {
+    let mut dirs = vec![];
+    if let Some(Value::Array(items)) = read_json(&extensions_dir.join("extensions.json")) {
+        for item in items {
+            let relative = item.get("relativeLocation").and_then(|v| v.as_str());
+            let location = item
+                .get("location")
+                .and_then(|v| v.get("fsPath").or_else(|| v.get("path")))
+                .and_then(|v| v.as_str());
+            match (relative, location) {
+                (Some(relative), _) => dirs.push(extensions_dir.join(relative)),
+                (None, Some(location)) => dirs.push(PathBuf::from(location)),
+                _ => {}
+            }
+        }
+        return dirs;
+    }
+    if let Ok(entries) = std::fs::read_dir(extensions_dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.join("package.json").exists() {
+                dirs.push(path);
+            }
+        }
+    }
+    dirs
+}
+
+fn read_extension(dir: &Path, disabled: &HashSet<String>) -> Option<ExtensionInfo> {
+    let package = read_json(&dir.join("package.json"))?;
+    let field = |name: &str| {
+        package
+            .get(name)
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string()
+    };
+    let publisher = field("publisher");
+    let name = field("name");
+    if publisher.is_empty() || name.is_empty() {
+        return None;
+    }
+    let id = format!("{}.{}", publisher, name).to_lowercase();
+    let display_name = match field("displayName") {
+        display_name if display_name.is_empty() || display_name.starts_with('%') => name,
+        display_name => display_name,
+    };
+    Some(ExtensionInfo {
+        enabled: !disabled.contains(&id),
+        id,
+        display_name,
+        publisher,
+        version: field("version"),
+        size: dir_size(dir),
+    })
+}
+
+// 扫描本地安装的扩展
+pub fn list_extensions(profile: &EditorProfile) -> Vec<ExtensionInfo> {
+    let extensions_dir = get_extensions_dir(profile);
+    let obsolete = read_obsolete_dirs(&extensions_dir);
+    let disabled = read_disabled_ids(profile);
+    let mut extensions: Vec<ExtensionInfo> = list_extension_dirs(&extensions_dir)
+        .iter()
+        .filter(|dir| {
+            let dir_name = dir
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default();
+            !obsolete.contains(&dir_name)
+        })
+        .filter_map(|dir| read_extension(dir, &disabled))
+        .collect();
+    extensions.sort_by(|a, b| a.id.cmp(&b.id));
+    extensions.dedup_by(|a, b| a.id == b.id);
+    extensions
+}
+
+pub fn build_manifest(profile: &EditorProfile) -> ExtensionManifest {
+    ExtensionManifest {
+        editor: profile.id.clone(),
+        exported_at: format_utc(now_secs()),
+        extensions: list_extensions(profile)
+            .into_iter()
+            .map(|ext| ManifestEntry {
+                id: ext.id,
+                version: ext.version,
+            })
+            .collect(),
+    }
+}
+
+pub fn default_manifest_path(profile: &EditorProfile) -> PathBuf {
+    let dir = get_app_data_dir().join("exports");
+    let _ = std::fs::create_dir_all(&dir);
+    dir.join(format!(
+        "extensions-{}-{}.json",
+        profile.id,
+        file_stamp(now_secs())
+    ))
+}
+
+// 导出扩展清单，返回文件路径
+pub fn export_manifest(profile: &EditorProfile, target: &Path) -> Result<String, String> {
+    if let Some(parent) = target.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let json_str =
+        serde_json::to_string_pretty(&build_manifest(profile)).map_err(|e| e.to_string())?;
+    std::fs::write(target, json_str).map_err(|e| e.to_string())?;
+    Ok(target.to_string_lossy().to_string())
+}
+
+pub fn read_manifest(path: &Path) -> Result<ExtensionManifest, String> {
+    let contents = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+    serde_json::from_str::<ExtensionManifest>(&contents)
+        .map_err(|e| t_args("error.manifest_parse", &[&e.to_string()]))
+}
+
+// 对比本地扩展与清单
+pub fn compare_with_manifest(
+    profile: &EditorProfile,
+    manifest: &ExtensionManifest,
+) -> ExtensionDiff {
+    let local = list_extensions(profile);
+    let mut missing = vec![];
+    let mut version_mismatch = vec![];
+    for entry in &manifest.extensions {
+        let id = entry.id.to_lowercase();
+        match local.iter().find(|ext| ext.id == id) {
+            None => missing.push(entry.clone()),
+            Some(ext) if ext.version != entry.version => version_mismatch.push(VersionMismatch {
+                id,
+                local_version: ext.version.clone(),
+                manifest_version: entry.version.clone(),
+            }),
+            Some(_) => {}
+        }
+    }
+    let extra = local
+        .iter()
+        .filter(|ext| {
+            !manifest
+                .extensions
+                .iter()
+                .any(|entry| entry.id.to_lowercase() == ext.id)
+        })
+        .map(|ext| ManifestEntry {
+            id: ext.id.clone(),
+            version: ext.version.clone(),
+        })
+        .collect();
+    ExtensionDiff {
+        missing,
+        version_mismatch,
+        extra,
+    }
+}
+
+// 通过编辑器命令行安装扩展，spec 为 id 或 id@version
+pub fn install_extension(cli: &Path, spec: &str) -> bool {
+    let mut command = Command::new(cli);
+    command.args(["--install-extension", spec, "--force"]);
+    #[cfg(target_os = "windows")]
+    command.creation_flags(CREATE_NO_WINDOW);
+    command
+        .output()
+        .map(|output| output.status.success())
+        .unwrap_or(false)
+}
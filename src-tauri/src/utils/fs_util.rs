@@ -0,0 +1,120 @@
+use crate::utils::time_util::now_secs;
+use std::path::{Path, PathBuf};
+
+// 递归计算目录大小（字节），不跟随符号链接
+pub fn dir_size(path: &Path) -> u64 {
+    let metadata = match std::fs::symlink_metadata(path) {
+        Ok(metadata) => metadata,
+        Err(_) => return 0,
+    };
+    if !metadata.is_dir() {
+        return metadata.len();
+    }
+    let mut size = 0;
+    if let Ok(entries) = std::fs::read_dir(path) {
+        for entry in entries.flatten() {
+            size += dir_size(&entry.path());
+        }
+    }
+    size
+}
+
+// 递归复制目录，目标中已有的同名文件会被覆盖
+pub fn copy_dir_all(src: &Path, dst: &Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(dst)?;
+    for entry in std::fs::read_dir(src)? {
+        let entry = entry?;
+        let target = dst.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir_all(&entry.path(), &target)?;
+        } else {
+            std::fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
+
+// 复制文件或目录，不存在时返回 false
+pub fn copy_path(src: &Path, dst: &Path) -> Result<bool, String> {
+    if !src.exists() {
+        return Ok(false);
+    }
+    if src.is_dir() {
+        copy_dir_all(src, dst).map_err(|e| e.to_string())?;
+    } else {
+        if let Some(parent) = dst.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        std::fs::copy(src, dst).map_err(|e| e.to_string())?;
+    }
+    Ok(true)
+}
+
+// 删除文件或目录，不存在时忽略
+pub fn remove_path(path: &Path) -> std::io::Result<()> {
+    if path.is_dir() {
+        std::fs::remove_dir_all(path)
+    } else if path.exists() {
+        std::fs::remove_file(path)
+    } else {
+        Ok(())
+    }
+}
+
+// 系统临时目录下的工作目录，离开作用域时自动删除
+pub struct TempDir {
+    pub path: PathBuf,
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
+
+pub fn create_temp_dir(prefix: &str) -> Result<TempDir, String> {
+    let path = std::env::temp_dir().join("cursor-reset").join(format!(
+        "{}-{}-{}",
+        prefix,
+        now_secs(),
+        std::process::id()
+    ));
+    std::fs::create_dir_all(&path).map_err(|e| e.to_string())?;
+    Ok(TempDir { path })
+}
+
+// 将 file:// URI 转为本地路径，其它协议（如 vscode-remote://）返回 None
+pub fn file_uri_to_path(uri: &str) -> Option<PathBuf> {
+    let rest = uri.strip_prefix("file://")?;
+    let bytes = rest.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap_or_default();
+            if let Ok(byte) = u8::from_str_radix(hex, 16) {
+                decoded.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        decoded.push(bytes[i]);
+        i += 1;
+    }
+    let path = String::from_utf8_lossy(&decoded).to_string();
+
+    #[cfg(target_os = "windows")]
+    {
+        // file:///c:/Users/... → c:\Users\...，file://server/share → \\server\share
+        let path = match path.strip_prefix('/') {
+            Some(local) => local.to_string(),
+            None => format!("//{}", path),
+        };
+        Some(PathBuf::from(path.replace('/', "\\")))
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        Some(PathBuf::from(path))
+    }
+}
@@ -0,0 +1,80 @@
+use crate::utils::i18n_util::t_args;
+use std::path::Path;
+use std::process::Command;
+
+#[cfg(target_os = "windows")]
+use crate::utils::process_util::CREATE_NO_WINDOW;
+#[cfg(target_os = "windows")]
+use std::os::windows::process::CommandExt;
+
+// 提交时使用的固定身份，避免依赖用户的全局 git 配置
+const GIT_IDENTITY: [&str; 4] = [
+    "-c",
+    "user.name=cursor-reset",
+    "-c",
+    "user.email=cursor-reset@localhost",
+];
+
+fn git_command(repo: &Path) -> Command {
+    let mut command = Command::new("git");
+    command.arg("-C").arg(repo).args(GIT_IDENTITY);
+    #[cfg(target_os = "windows")]
+    command.creation_flags(CREATE_NO_WINDOW);
+    command
+}
+
+fn run(mut command: Command) -> Result<String, String> {
+    let output = command
+        .output()
+        .map_err(|e| t_args("error.git", &[&e.to_string()]))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        return Err(t_args("error.git", &[&stderr]));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+// 在仓库中执行 git 命令，返回标准输出
+pub fn git(repo: &Path, args: &[&str]) -> Result<String, String> {
+    let mut command = git_command(repo);
+    command.args(args);
+    run(command)
+}
+
+// 目录不是 git 仓库时初始化
+pub fn ensure_repo(repo: &Path) -> Result<(), String> {
+    std::fs::create_dir_all(repo).map_err(|e| e.to_string())?;
+    if !repo.join(".git").exists() {
+        git(repo, &["init", "--quiet"])?;
+    }
+    Ok(())
+}
+
+// 暂存全部改动并提交（允许空提交），返回提交哈希
+pub fn commit_all(repo: &Path, message: &str) -> Result<String, String> {
+    git(repo, &["add", "-A"])?;
+    git(repo, &["commit", "--quiet", "--allow-empty", "-m", message])?;
+    Ok(git(repo, &["rev-parse", "HEAD"])?.trim().to_string())
+}
+
+// 将某次提交的文件导出到指定目录，不影响仓库的工作区和暂存区
+pub fn export_commit(repo: &Path, commit: &str, target: &Path) -> Result<(), String> {
+    let index_file = target.join(".git-export-index");
+    let mut prefix = target.to_string_lossy().to_string();
+    if !prefix.ends_with(std::path::MAIN_SEPARATOR) {
+        prefix.push(std::path::MAIN_SEPARATOR);
+    }
+    let mut read_tree = git_command(repo);
+    read_tree
+        .env("GIT_INDEX_FILE", &index_file)
+        .args(["read-tree", commit]);
+    run(read_tree)?;
+    let mut checkout = git_command(repo);
+    checkout
+        .env("GIT_INDEX_FILE", &index_file)
+        .args(["checkout-index", "-a", "-f"])
+        .arg(format!("--prefix={}", prefix));
+    run(checkout)?;
+    let _ = std::fs::remove_file(&index_file);
+    Ok(())
+}
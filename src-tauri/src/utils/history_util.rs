@@ -0,0 +1,39 @@
+use crate::model::history_model::HistoryEntry;
+use crate::utils::config_util::get_app_data_dir;
+use crate::utils::redact_util::redact_text;
+use crate::utils::time_util::{format_utc, now_secs};
+use std::path::PathBuf;
+
+const HISTORY_FILE_NAME: &str = "history.json";
+// 最多保留的记录条数
+const MAX_HISTORY: usize = 500;
+
+pub fn get_history_path() -> PathBuf {
+    get_app_data_dir().join(HISTORY_FILE_NAME)
+}
+
+// 读取历史记录（按时间顺序），文件不存在或解析失败时返回空列表
+pub fn load_history() -> Vec<HistoryEntry> {
+    std::fs::read_to_string(get_history_path())
+        .ok()
+        .and_then(|contents| serde_json::from_str::<Vec<HistoryEntry>>(&contents).ok())
+        .unwrap_or_default()
+}
+
+// 追加一条记录，写入失败时忽略
+pub fn record(action: &str, editor: &str, success: bool, detail: &str) {
+    let mut history = load_history();
+    history.push(HistoryEntry {
+        time: format_utc(now_secs()),
+        action: action.to_string(),
+        editor: editor.to_string(),
+        success,
+        detail: redact_text(detail),
+    });
+    if history.len() > MAX_HISTORY {
+        history.drain(..history.len() - MAX_HISTORY);
+    }
+    if let Ok(json_str) = serde_json::to_string_pretty(&history) {
+        let _ = std::fs::write(get_history_path(), json_str);
+    }
+}
@@ -0,0 +1,157 @@
+use crate::model::editor_model::EditorProfile;
+use crate::model::hook_model::{HookCommand, HookContext, HookEvent, HookResult};
+use crate::utils::config_util::load_config;
+use crate::utils::i18n_util::t_args;
+use crate::utils::log_util;
+use crate::utils::time_util::{format_utc, now_secs};
+use std::io::{Read, Write};
+use std::process::{Child, Command, Stdio};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+#[cfg(target_os = "windows")]
+use crate::utils::process_util::CREATE_NO_WINDOW;
+#[cfg(target_os = "macos")]
+use std::os::unix::process::CommandExt;
+#[cfg(target_os = "windows")]
+use std::os::windows::process::CommandExt;
+
+// 等待命令结束时的轮询间隔
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+fn shell_command(command: &str) -> Command {
+    #[cfg(target_os = "windows")]
+    {
+        let mut shell = Command::new("cmd");
+        shell.args(["/C", command]).creation_flags(CREATE_NO_WINDOW);
+        shell
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        // 放到单独的进程组中，超时时可以连同子进程一起结束
+        let mut shell = Command::new("sh");
+        shell.args(["-c", command]).process_group(0);
+        shell
+    }
+}
+
+// 结束命令及其启动的子进程，否则子进程仍持有输出管道，读取线程会一直等待
+fn kill_tree(child: &mut Child) {
+    #[cfg(target_os = "windows")]
+    let _ = Command::new("taskkill")
+        .args(["/F", "/T", "/PID", &child.id().to_string()])
+        .creation_flags(CREATE_NO_WINDOW)
+        .output();
+
+    #[cfg(target_os = "macos")]
+    let _ = Command::new("kill")
+        .args(["-9", &format!("-{}", child.id())])
+        .output();
+
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+// 在后台线程读取输出，避免输出过多时管道写满导致命令阻塞
+fn read_pipe<R: Read + Send + 'static>(pipe: Option<R>) -> JoinHandle<String> {
+    std::thread::spawn(move || {
+        let mut output = vec![];
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut output);
+        }
+        String::from_utf8_lossy(&output).trim().to_string()
+    })
+}
+
+// 等待命令结束，超时后结束进程，返回 (退出码, 是否超时)
+fn wait_with_timeout(child: &mut Child, timeout: Duration) -> (Option<i32>, bool) {
+    let start = Instant::now();
+    loop {
+        match child.try_wait() {
+            Ok(Some(status)) => return (status.code(), false),
+            Ok(None) if start.elapsed() < timeout => std::thread::sleep(POLL_INTERVAL),
+            Ok(None) => {
+                kill_tree(child);
+                return (None, true);
+            }
+            Err(_) => return (None, false),
+        }
+    }
+}
+
+// 执行一条钩子命令，上下文以 JSON 形式写入标准输入
+pub fn run_hook(hook: &HookCommand, context: &HookContext) -> Result<HookResult, String> {
+    let input = serde_json::to_string(context).map_err(|e| e.to_string())?;
+    let mut child = shell_command(&hook.command)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| e.to_string())?;
+    // 命令可能不读取标准输入，写入失败（管道已关闭）不算错误
+    if let Some(mut stdin) = child.stdin.take() {
+        let _ = stdin.write_all(input.as_bytes());
+    }
+    let stdout = read_pipe(child.stdout.take());
+    let stderr = read_pipe(child.stderr.take());
+    let (exit_code, timed_out) =
+        wait_with_timeout(&mut child, Duration::from_secs(hook.timeout_secs));
+    Ok(HookResult {
+        event: context.event,
+        command: hook.command.clone(),
+        exit_code,
+        timed_out,
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+    })
+}
+
+fn failure_detail(result: &HookResult) -> String {
+    if result.timed_out {
+        return t_args("hook.timed_out", &[&result.command]);
+    }
+    let code = result
+        .exit_code
+        .map(|code| code.to_string())
+        .unwrap_or_default();
+    t_args("hook.exit_code", &[&result.command, &code, &result.stderr])
+}
+
+// 执行配置中指定节点的钩子，未配置时直接返回成功。
+// 前置钩子失败时返回错误以取消操作，后置钩子失败只记录日志
+pub fn run_configured(
+    event: HookEvent,
+    profile: &EditorProfile,
+    target: &str,
+    success: Option<bool>,
+) -> Result<(), String> {
+    let config = load_config();
+    let hook = match config.hooks.get(event) {
+        Some(hook) => hook,
+        None => return Ok(()),
+    };
+    let context = HookContext {
+        event,
+        editor: profile.id.clone(),
+        editor_name: profile.name.clone(),
+        time: format_utc(now_secs()),
+        target: target.to_string(),
+        success,
+    };
+    let detail = match run_hook(hook, &context) {
+        Ok(result) if result.success() => {
+            log_util::info(&t_args("log.hook", &[event.name(), &hook.command]));
+            return Ok(());
+        }
+        Ok(result) => failure_detail(&result),
+        Err(e) => e,
+    };
+    let message = t_args("error.hook_failed", &[event.name(), &detail]);
+    log_util::error(&message);
+    if event.is_pre() {
+        Err(message)
+    } else {
+        Ok(())
+    }
+}
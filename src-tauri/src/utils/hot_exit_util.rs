@@ -0,0 +1,182 @@
+use crate::model::editor_model::EditorProfile;
+use crate::model::hot_exit_model::{HotExitBackup, HotExitWorkspace};
+use crate::utils::editor_util::get_data_dir;
+use crate::utils::fs_util::file_uri_to_path;
+use crate::utils::i18n_util::{t, t_args};
+use crate::utils::time_util::{format_utc, modified_secs};
+use crate::utils::workspace_util::list_workspace_storage;
+use std::path::{Path, PathBuf};
+
+// 预览的最大字符数
+const PREVIEW_CHARS: usize = 200;
+
+// 编辑器的 Backups 目录（热退出时保存未保存内容的位置）
+pub fn get_backups_dir(profile: &EditorProfile) -> Result<PathBuf, String> {
+    get_data_dir(profile)
+        .map(|dir| dir.join("Backups"))
+        .ok_or_else(|| t("error.data_dir_not_found"))
+}
+
+// 备份文件第一行是 "<原始 URI> <元数据 JSON>"，之后是文件内容
+fn split_backup(bytes: &[u8]) -> (String, &[u8]) {
+    let header_end = bytes
+        .iter()
+        .position(|b| *b == b'\n')
+        .unwrap_or(bytes.len());
+    let header = String::from_utf8_lossy(&bytes[..header_end]).to_string();
+    let uri = header.split(' ').next().unwrap_or_default().to_string();
+    let content = bytes.get(header_end + 1..).unwrap_or_default();
+    (uri, content)
+}
+
+fn read_backup(backups_dir: &Path, path: &Path) -> Option<HotExitBackup> {
+    let bytes = std::fs::read(path).ok()?;
+    let (original_uri, content) = split_backup(&bytes);
+    if original_uri.is_empty() {
+        return None;
+    }
+    let relative = path.strip_prefix(backups_dir).ok()?;
+    let scheme = relative
+        .parent()
+        .and_then(|parent| parent.file_name())
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    Some(HotExitBackup {
+        id: relative.to_string_lossy().replace('\\', "/"),
+        path: path.to_string_lossy().to_string(),
+        scheme,
+        original_uri,
+        size: content.len() as u64,
+        modified_at: format_utc(modified_secs(path)),
+        preview: String::from_utf8_lossy(content)
+            .chars()
+            .take(PREVIEW_CHARS)
+            .collect(),
+    })
+}
+
+// 按工作区列出可恢复的备份，没有备份的工作区不返回
+pub fn list_hot_exit_backups(profile: &EditorProfile) -> Result<Vec<HotExitWorkspace>, String> {
+    let backups_dir = get_backups_dir(profile)?;
+    // workspaceStorage 与 Backups 使用相同的工作区 id，借此找到工作区 URI
+    let workspaces = list_workspace_storage(profile).unwrap_or_default();
+    let mut result = vec![];
+    let entries = match std::fs::read_dir(&backups_dir) {
+        Ok(entries) => entries,
+        Err(_) => return Ok(result),
+    };
+    for workspace_dir in entries.flatten().map(|entry| entry.path()) {
+        if !workspace_dir.is_dir() {
+            continue;
+        }
+        let mut backups = vec![];
+        for scheme_dir in std::fs::read_dir(&workspace_dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+        {
+            for file in std::fs::read_dir(scheme_dir.path())
+                .into_iter()
+                .flatten()
+                .flatten()
+            {
+                if let Some(backup) = read_backup(&backups_dir, &file.path()) {
+                    backups.push(backup);
+                }
+            }
+        }
+        if backups.is_empty() {
+            continue;
+        }
+        backups.sort_by(|a, b| b.modified_at.cmp(&a.modified_at));
+        let id = workspace_dir
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let workspace_uri = workspaces
+            .iter()
+            .find(|workspace| workspace.id == id)
+            .map(|workspace| workspace.uri.clone())
+            .unwrap_or_default();
+        result.push(HotExitWorkspace {
+            id,
+            workspace_uri,
+            backups,
+        });
+    }
+    Ok(result)
+}
+
+// 待恢复的备份数量
+pub fn count_hot_exit_backups(profile: &EditorProfile) -> usize {
+    list_hot_exit_backups(profile)
+        .map(|workspaces| workspaces.iter().map(|ws| ws.backups.len()).sum())
+        .unwrap_or(0)
+}
+
+// 导出时使用的文件名：取原始文件名，未命名文件用 untitled 名称
+fn export_file_name(backup: &HotExitBackup) -> String {
+    let name = match file_uri_to_path(&backup.original_uri) {
+        Some(path) => path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default(),
+        None => backup
+            .original_uri
+            .rsplit([':', '/'])
+            .next()
+            .unwrap_or_default()
+            .to_string(),
+    };
+    let name: String = name
+        .chars()
+        .map(|c| if "<>:\"/\\|?*".contains(c) { '_' } else { c })
+        .collect();
+    if name.is_empty() {
+        "backup.txt".to_string()
+    } else {
+        name
+    }
+}
+
+// 文件已存在时追加序号，避免互相覆盖
+fn unique_path(dir: &Path, name: &str) -> PathBuf {
+    let candidate = dir.join(name);
+    if !candidate.exists() {
+        return candidate;
+    }
+    let (stem, ext) = match name.rsplit_once('.') {
+        Some((stem, ext)) if !stem.is_empty() => (stem, format!(".{}", ext)),
+        _ => (name, "".to_string()),
+    };
+    (1..)
+        .map(|index| dir.join(format!("{} ({}){}", stem, index, ext)))
+        .find(|path| !path.exists())
+        .unwrap_or(candidate)
+}
+
+// 将选中的备份内容导出到目标文件夹，返回导出的文件路径
+pub fn export_hot_exit_backups(
+    profile: &EditorProfile,
+    ids: &[String],
+    target_dir: &Path,
+) -> Result<Vec<String>, String> {
+    let backups: Vec<HotExitBackup> = list_hot_exit_backups(profile)?
+        .into_iter()
+        .flat_map(|workspace| workspace.backups)
+        .collect();
+    std::fs::create_dir_all(target_dir).map_err(|e| e.to_string())?;
+    let mut exported = vec![];
+    for id in ids {
+        let backup = backups
+            .iter()
+            .find(|backup| &backup.id == id)
+            .ok_or_else(|| t_args("error.hot_exit_not_found", &[id]))?;
+        let bytes = std::fs::read(&backup.path).map_err(|e| e.to_string())?;
+        let (_, content) = split_backup(&bytes);
+        let target = unique_path(target_dir, &export_file_name(backup));
+        std::fs::write(&target, content).map_err(|e| e.to_string())?;
+        exported.push(target.to_string_lossy().to_string());
+    }
+    Ok(exported)
+}
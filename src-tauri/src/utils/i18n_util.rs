@@ -0,0 +1,420 @@
+use crate::utils::config_util::load_config;
+
+// 后端支持的界面语言
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Language {
+    ZhCn,
+    En,
+}
+
+impl Language {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::ZhCn => "zh-CN",
+            Language::En => "en",
+        }
+    }
+
+    // 解析 "zh-CN" / "zh_CN.UTF-8" / "en-US" 等写法，无法识别时返回 None
+    pub fn parse(code: &str) -> Option<Language> {
+        let code = code.trim().to_lowercase();
+        if code.starts_with("zh") {
+            Some(Language::ZhCn)
+        } else if code.starts_with("en") {
+            Some(Language::En)
+        } else {
+            None
+        }
+    }
+}
+
+// 中文文案，"{}" 为按顺序替换的占位符
+const ZH_CN: &[(&str, &str)] = &[
+    ("app.title", "Cursor重置工具 v2.0"),
+    ("install.user_email", "用户({})"),
+    ("log.reset", "重置机器码: {}"),
+    ("log.restart", "重启 Cursor: {}"),
+    ("log.export_diagnostics", "导出诊断包: {}"),
+    ("error.export_diagnostics", "导出诊断包失败: {}"),
+    ("error.storage_not_found", "未找到 storage.json"),
+    ("error.unknown_editor", "未知的编辑器: {}"),
+    ("error.data_dir_not_found", "未找到编辑器的用户数据目录"),
+    ("error.config_parse", "无法解析配置文件: {}"),
+    ("error.telemetry_level", "无效的遥测级别: {}"),
+    ("log.telemetry_level", "设置遥测级别: {}"),
+    ("log.crash_reporter", "设置崩溃报告: {}"),
+    (
+        "error.storage_key_not_found",
+        "storage.json 中不存在该路径: {}",
+    ),
+    ("log.backup", "备份 storage.json: {}"),
+    ("error.backup", "备份 storage.json 失败: {}"),
+    ("tray.status_running", "Cursor 正在运行"),
+    ("tray.status_stopped", "Cursor 未运行"),
+    ("tray.show", "显示主界面"),
+    ("tray.backup", "备份机器码"),
+    ("tray.restart", "重启 Cursor"),
+    ("tray.open_logs", "打开日志目录"),
+    ("tray.quit", "退出"),
+    ("error.manifest_parse", "无法解析扩展清单: {}"),
+    ("log.export_extensions", "导出扩展清单: {}"),
+    ("log.settings_snapshot", "创建配置快照: {}"),
+    (
+        "log.settings_restore",
+        "从配置快照恢复: {}，原配置已备份到 {}",
+    ),
+    ("error.git", "git 命令执行失败: {}"),
+    (
+        "error.cli_not_found",
+        "未找到编辑器命令行工具，无法安装扩展",
+    ),
+    ("error.settings_snapshot_not_found", "未找到配置快照: {}"),
+    ("error.editor_running", "{} 正在运行，请先关闭后再试"),
+    ("log.clean", "清理缓存: {}，释放 {} 字节"),
+    ("log.prune_workspaces", "清理工作区存储: {} 个，已备份到 {}"),
+    ("error.workspace_not_found", "未找到工作区存储目录: {}"),
+    ("error.log_session_not_found", "未找到日志会话: {}"),
+    ("error.crash_dump_not_found", "未找到崩溃转储: {}"),
+    ("log.archive_crashes", "打包崩溃转储: {}"),
+    ("log.delete_crashes", "删除崩溃转储: {} 个"),
+    (
+        "log.update_recent",
+        "更新最近打开列表: 删除 {} 项，原数据已备份到 {}",
+    ),
+    (
+        "error.state_db_not_found",
+        "未找到编辑器的全局状态库 state.vscdb",
+    ),
+    ("error.hot_exit_not_found", "未找到未保存文件的备份: {}"),
+    (
+        "error.hot_exit_pending",
+        "有 {} 个未保存文件的备份，强制重启可能丢失这些内容",
+    ),
+    ("log.export_hot_exit", "导出 {} 个未保存文件到 {}"),
+    ("error.profile_name", "无效的配置名: {}"),
+    ("error.profile_exists", "配置已存在: {}"),
+    ("error.profile_not_found", "未找到配置: {}"),
+    ("error.executable_not_found", "未找到 {} 的可执行文件"),
+    ("log.launch_profile", "以配置 {} 启动: {}"),
+    ("log.delete_profile", "删除配置: {}"),
+    ("error.launch_log_level", "无效的日志级别: {}"),
+    ("error.launch_path", "无效的路径: {}"),
+    ("error.launch_env", "无效的环境变量名: {}"),
+    ("log.launch", "启动编辑器: {}"),
+    ("error.state_key_not_found", "state.vscdb 中不存在该键: {}"),
+    (
+        "log.maintain_state",
+        "维护 state.vscdb: {} 个数据库，{} 字节 → {} 字节，已备份到 {}",
+    ),
+    ("doctor.install_found", "找到编辑器安装"),
+    (
+        "doctor.install_found.remedy",
+        "确认编辑器已安装；如安装在非默认位置，请在 editors.json 中补充安装路径",
+    ),
+    ("doctor.data_dir_found", "找到用户数据目录"),
+    (
+        "doctor.data_dir_found.remedy",
+        "至少启动一次编辑器以生成用户数据目录",
+    ),
+    ("doctor.storage_parses", "storage.json 可以解析"),
+    (
+        "doctor.storage_parses.remedy",
+        "从 backups 目录恢复最近的 storage.json 备份，或删除后重启编辑器重新生成",
+    ),
+    ("doctor.identifiers_valid", "机器码格式正确"),
+    (
+        "doctor.identifiers_valid.remedy",
+        "重置机器码以重新生成格式正确的值",
+    ),
+    ("doctor.storage_writable", "storage.json 可写"),
+    (
+        "doctor.storage_writable.remedy",
+        "取消 storage.json 的只读属性，或检查当前用户对该文件的权限",
+    ),
+    ("doctor.app_files_intact", "应用文件未被修改"),
+    (
+        "doctor.app_files_intact.remedy",
+        "应用文件与 product.json 中的校验和不一致，重新安装编辑器可消除“安装已损坏”提示",
+    ),
+    ("doctor.disk_space", "磁盘空间充足"),
+    (
+        "doctor.disk_space.remedy",
+        "清理缓存或释放磁盘空间，空间不足可能导致配置写入失败",
+    ),
+    ("doctor.disk_space.free", "可用 {} MB"),
+    ("doctor.no_stale_processes", "没有残留进程"),
+    (
+        "doctor.no_stale_processes.remedy",
+        "编辑器窗口已关闭但仍有进程在后台运行，可在托盘中重启编辑器或手动结束这些进程",
+    ),
+    ("report.title", "Cursor 状态报告"),
+    ("report.summary", "概览"),
+    ("report.install", "安装信息"),
+    ("report.device", "机器码信息"),
+    ("report.checks", "健康检查"),
+    ("report.history", "最近操作"),
+    ("report.field", "项目"),
+    ("report.value", "值"),
+    ("report.status", "状态"),
+    ("report.check", "检查项"),
+    ("report.detail", "详情"),
+    ("report.remedy", "处理建议"),
+    ("report.time", "时间"),
+    ("report.action", "操作"),
+    ("report.empty", "无"),
+    ("log.export_report", "导出状态报告: {}"),
+    ("error.export_report", "导出状态报告失败: {}"),
+    ("cli.usage", "用法: cursor-reset report [--format json|md|html] [--output 文件] [--editor 编辑器] [--mask full|partial|hashed]"),
+    ("cli.missing_value", "参数 {} 缺少取值"),
+    ("cli.invalid_value", "参数 {} 的取值无效: {}"),
+    ("cli.unknown_arg", "未知参数: {}"),
+    ("log.hook", "执行钩子 {}: {}"),
+    ("error.hook_failed", "钩子 {} 执行失败: {}"),
+    ("hook.timed_out", "命令超时: {}"),
+    ("hook.exit_code", "命令 {} 退出码 {}: {}"),
+];
+
+// 英文文案，键必须与中文完全一致
+const EN: &[(&str, &str)] = &[
+    ("app.title", "Cursor Reset Tool v2.0"),
+    ("install.user_email", "User ({})"),
+    ("log.reset", "Reset device identifiers: {}"),
+    ("log.restart", "Restart Cursor: {}"),
+    ("log.export_diagnostics", "Exported diagnostics bundle: {}"),
+    (
+        "error.export_diagnostics",
+        "Failed to export diagnostics bundle: {}",
+    ),
+    ("error.storage_not_found", "storage.json not found"),
+    ("error.unknown_editor", "Unknown editor: {}"),
+    (
+        "error.data_dir_not_found",
+        "Editor user data directory not found",
+    ),
+    ("error.config_parse", "Unable to parse config file: {}"),
+    ("error.telemetry_level", "Invalid telemetry level: {}"),
+    ("log.telemetry_level", "Set telemetry level: {}"),
+    ("log.crash_reporter", "Set crash reporter: {}"),
+    (
+        "error.storage_key_not_found",
+        "Path not found in storage.json: {}",
+    ),
+    ("log.backup", "Backed up storage.json: {}"),
+    ("error.backup", "Failed to back up storage.json: {}"),
+    ("tray.status_running", "Cursor is running"),
+    ("tray.status_stopped", "Cursor is not running"),
+    ("tray.show", "Show window"),
+    ("tray.backup", "Back up identifiers"),
+    ("tray.restart", "Restart Cursor"),
+    ("tray.open_logs", "Open log folder"),
+    ("tray.quit", "Quit"),
+    (
+        "error.manifest_parse",
+        "Unable to parse extension manifest: {}",
+    ),
+    ("log.export_extensions", "Exported extension manifest: {}"),
+    ("log.settings_snapshot", "Created settings snapshot: {}"),
+    (
+        "log.settings_restore",
+        "Restored settings snapshot {}, previous settings backed up to {}",
+    ),
+    ("error.git", "git command failed: {}"),
+    (
+        "error.cli_not_found",
+        "Editor command line tool not found, unable to install extensions",
+    ),
+    (
+        "error.settings_snapshot_not_found",
+        "Settings snapshot not found: {}",
+    ),
+    (
+        "error.editor_running",
+        "{} is running, please close it and try again",
+    ),
+    ("log.clean", "Cleaned caches: {}, freed {} bytes"),
+    (
+        "log.prune_workspaces",
+        "Pruned {} workspace storage entries, backed up to {}",
+    ),
+    (
+        "error.workspace_not_found",
+        "Workspace storage entry not found: {}",
+    ),
+    ("error.log_session_not_found", "Log session not found: {}"),
+    ("error.crash_dump_not_found", "Crash dump not found: {}"),
+    ("log.archive_crashes", "Archived crash dumps: {}"),
+    ("log.delete_crashes", "Deleted {} crash dumps"),
+    (
+        "log.update_recent",
+        "Updated recently opened list: removed {} entries, backed up to {}",
+    ),
+    (
+        "error.state_db_not_found",
+        "Editor global state database state.vscdb not found",
+    ),
+    (
+        "error.hot_exit_not_found",
+        "Unsaved file backup not found: {}",
+    ),
+    (
+        "error.hot_exit_pending",
+        "{} unsaved file backups are pending, a forced restart may discard them",
+    ),
+    ("log.export_hot_exit", "Exported {} unsaved files to {}"),
+    ("error.profile_name", "Invalid profile name: {}"),
+    ("error.profile_exists", "Profile already exists: {}"),
+    ("error.profile_not_found", "Profile not found: {}"),
+    ("error.executable_not_found", "Executable for {} not found"),
+    ("log.launch_profile", "Launched with profile {}: {}"),
+    ("log.delete_profile", "Deleted profile: {}"),
+    ("error.launch_log_level", "Invalid log level: {}"),
+    ("error.launch_path", "Invalid path: {}"),
+    ("error.launch_env", "Invalid environment variable name: {}"),
+    ("log.launch", "Launched editor: {}"),
+    (
+        "error.state_key_not_found",
+        "Key not found in state.vscdb: {}",
+    ),
+    (
+        "log.maintain_state",
+        "Maintained state.vscdb: {} databases, {} bytes -> {} bytes, backed up to {}",
+    ),
+    ("doctor.install_found", "Editor installation found"),
+    ("doctor.install_found.remedy", "Make sure the editor is installed; if it is in a non-default location, add the path to editors.json"),
+    ("doctor.data_dir_found", "User data directory found"),
+    ("doctor.data_dir_found.remedy", "Start the editor at least once to create its user data directory"),
+    ("doctor.storage_parses", "storage.json parses"),
+    ("doctor.storage_parses.remedy", "Restore the latest storage.json backup from the backups folder, or delete it and restart the editor to regenerate it"),
+    ("doctor.identifiers_valid", "Device identifiers are well-formed"),
+    ("doctor.identifiers_valid.remedy", "Reset the device identifiers to regenerate well-formed values"),
+    ("doctor.storage_writable", "storage.json is writable"),
+    ("doctor.storage_writable.remedy", "Clear the read-only flag on storage.json or check the current user's permissions"),
+    ("doctor.app_files_intact", "Application files are unmodified"),
+    ("doctor.app_files_intact.remedy", "Application files do not match the checksums in product.json; reinstall the editor to clear the corrupt installation warning"),
+    ("doctor.disk_space", "Enough disk space"),
+    ("doctor.disk_space.remedy", "Clean caches or free disk space; low space can make settings writes fail"),
+    ("doctor.disk_space.free", "{} MB free"),
+    ("doctor.no_stale_processes", "No stale processes"),
+    ("doctor.no_stale_processes.remedy", "Editor processes are still running without a window; restart the editor from the tray or end these processes manually"),
+    ("report.title", "Cursor status report"),
+    ("report.summary", "Summary"),
+    ("report.install", "Installation"),
+    ("report.device", "Device identifiers"),
+    ("report.checks", "Health checks"),
+    ("report.history", "Recent history"),
+    ("report.field", "Field"),
+    ("report.value", "Value"),
+    ("report.status", "Status"),
+    ("report.check", "Check"),
+    ("report.detail", "Detail"),
+    ("report.remedy", "Remedy"),
+    ("report.time", "Time"),
+    ("report.action", "Action"),
+    ("report.empty", "None"),
+    ("log.export_report", "Exported status report: {}"),
+    ("error.export_report", "Failed to export status report: {}"),
+    ("cli.usage", "Usage: cursor-reset report [--format json|md|html] [--output FILE] [--editor ID] [--mask full|partial|hashed]"),
+    ("cli.missing_value", "Missing value for {}"),
+    ("cli.invalid_value", "Invalid value for {}: {}"),
+    ("cli.unknown_arg", "Unknown argument: {}"),
+    ("log.hook", "Ran hook {}: {}"),
+    ("error.hook_failed", "Hook {} failed: {}"),
+    ("hook.timed_out", "Command timed out: {}"),
+    ("hook.exit_code", "Command {} exited with code {}: {}"),
+];
+
+fn catalog(language: Language) -> &'static [(&'static str, &'static str)] {
+    match language {
+        Language::ZhCn => ZH_CN,
+        Language::En => EN,
+    }
+}
+
+// 当前语言：优先使用配置，配置为空时跟随系统语言，都无法识别时使用中文
+pub fn current_language() -> Language {
+    let config = load_config();
+    if let Some(language) = Language::parse(&config.language) {
+        return language;
+    }
+    sys_locale::get_locale()
+        .and_then(|locale| Language::parse(&locale))
+        .unwrap_or(Language::ZhCn)
+}
+
+pub fn translate(language: Language, key: &str) -> String {
+    catalog(language)
+        .iter()
+        .find(|(k, _)| *k == key)
+        .map(|(_, v)| v.to_string())
+        .unwrap_or_else(|| key.to_string())
+}
+
+// 按当前语言翻译
+pub fn t(key: &str) -> String {
+    translate(current_language(), key)
+}
+
+// 按当前语言翻译并依次替换 "{}" 占位符
+pub fn t_args(key: &str, args: &[&str]) -> String {
+    let mut text = t(key);
+    for arg in args {
+        text = text.replacen("{}", arg, 1);
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(language: Language) -> Vec<&'static str> {
+        catalog(language).iter().map(|(k, _)| *k).collect()
+    }
+
+    #[test]
+    fn every_key_exists_in_both_catalogs() {
+        let zh_keys = keys(Language::ZhCn);
+        let en_keys = keys(Language::En);
+        for key in &zh_keys {
+            assert!(en_keys.contains(key), "en catalog is missing key {}", key);
+        }
+        for key in &en_keys {
+            assert!(
+                zh_keys.contains(key),
+                "zh-CN catalog is missing key {}",
+                key
+            );
+        }
+    }
+
+    #[test]
+    fn catalogs_have_no_duplicate_keys() {
+        for language in [Language::ZhCn, Language::En] {
+            let mut all = keys(language);
+            let total = all.len();
+            all.sort();
+            all.dedup();
+            assert_eq!(all.len(), total, "duplicate key in {}", language.code());
+        }
+    }
+
+    #[test]
+    fn placeholders_match_between_catalogs() {
+        for (key, zh) in ZH_CN {
+            let en = translate(Language::En, key);
+            assert_eq!(
+                zh.matches("{}").count(),
+                en.matches("{}").count(),
+                "placeholder count differs for {}",
+                key
+            );
+        }
+    }
+
+    #[test]
+    fn parses_locale_codes() {
+        assert_eq!(Language::parse("zh_CN.UTF-8"), Some(Language::ZhCn));
+        assert_eq!(Language::parse("en-US"), Some(Language::En));
+        assert_eq!(Language::parse(""), None);
+    }
+}
@@ -0,0 +1,271 @@
+use crate::model::cursor_model::CursorInstallInfo;
+use crate::model::editor_model::EditorProfile;
+use crate::utils::config_util::load_config;
+#[cfg(target_os = "macos")]
+use crate::utils::editor_log_util::find_first_line;
+#[cfg(target_os = "macos")]
+use crate::utils::editor_util::{get_data_dir, resolve_home_path};
+#[cfg(target_os = "macos")]
+use crate::utils::i18n_util::t_args;
+#[cfg(target_os = "macos")]
+use crate::utils::redact_util::redact_email;
+use crate::utils::redact_util::redact_name;
+use std::path::PathBuf;
+#[cfg(target_os = "macos")]
+use std::process::Command;
+
+#[cfg(target_os = "windows")]
+use winreg::enums::*;
+#[cfg(target_os = "windows")]
+use winreg::RegKey;
+
+// 获取编辑器安装信息 - 跨平台实现（未脱敏，仅供后端内部使用）
+pub fn find_install_info(profile: &EditorProfile) -> CursorInstallInfo {
+    let config = load_config();
+    let mut install_info =
+        CursorInstallInfo::new("".to_owned(), "".to_owned(), "".to_owned(), "".to_owned());
+    #[cfg(target_os = "windows")]
+    {
+        let hkcu = RegKey::predef(HKEY_CURRENT_USER);
+        for reg_path in &profile.windows_registry_keys {
+            let subkey = match hkcu.open_subkey(reg_path) {
+                Ok(subkey) => subkey,
+                Err(_) => continue,
+            };
+            if let Ok(install_path) = subkey.get_value::<String, _>("InstallLocation") {
+                install_info.install_path = install_path;
+            }
+            if let Ok(install_language) = subkey.get_value::<String, _>("Inno Setup: Language") {
+                install_info.install_language = install_language;
+            }
+            if let Ok(install_version) = subkey.get_value::<String, _>("DisplayVersion") {
+                install_info.install_version = install_version;
+            }
+            // 安装用户属于个人信息，只有用户主动开启后才读取
+            if config.collect_personal_data {
+                if let Ok(install_user) = subkey.get_value::<String, _>("Inno Setup: User") {
+                    install_info.install_user = redact_name(&install_user);
+                }
+            }
+            break;
+        }
+        return install_info;
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        // macOS 上按清单中的候选位置查找应用
+        for app_path in &profile.macos_app_paths {
+            let app_path = resolve_home_path(app_path);
+            if app_path.exists() {
+                install_info.install_path = app_path.to_string_lossy().to_string();
+                break;
+            }
+        }
+        // 如果没找到，尝试使用 mdfind 命令查找
+        if install_info.install_path.is_empty() && !profile.macos_bundle_id.is_empty() {
+            let query = format!("kMDItemCFBundleIdentifier == '{}'", profile.macos_bundle_id);
+            if let Ok(output) = Command::new("mdfind").args([&query]).output() {
+                if let Ok(stdout) = String::from_utf8(output.stdout) {
+                    let lines: Vec<&str> = stdout.lines().collect();
+                    if !lines.is_empty() {
+                        install_info.install_path = lines[0].to_string();
+                    }
+                }
+            }
+        }
+
+        // 获取版本信息
+        if !install_info.install_path.is_empty() {
+            // 从Info.plist获取版本信息
+            let info_plist_path = format!("{}/Contents/Info.plist", install_info.install_path);
+            if std::path::Path::new(&info_plist_path).exists() {
+                // 使用defaults命令读取版本信息
+                if let Ok(output) = Command::new("defaults")
+                    .args(["read", &info_plist_path, "CFBundleShortVersionString"])
+                    .output()
+                {
+                    if let Ok(version) = String::from_utf8(output.stdout) {
+                        install_info.install_version = version.trim().to_string();
+                    }
+                }
+
+                // 尝试多种方法获取编辑器的语言和用户信息
+                let cursor_config_dir = get_data_dir(profile)
+                    .map(|dir| dir.to_string_lossy().to_string())
+                    .unwrap_or_default();
+
+                // 1. 尝试从Preferences文件获取语言设置
+                let preferences_path = format!("{}/Preferences", cursor_config_dir);
+                if std::path::Path::new(&preferences_path).exists() {
+                    if let Ok(output) = Command::new("cat").arg(&preferences_path).output() {
+                        if let Ok(prefs_content) = String::from_utf8(output.stdout) {
+                            if let Some(lang_pos) = prefs_content.find("dictionaries\":") {
+                                let lang_start = lang_pos + 14; // dictionaries":[" 的长度
+                                if let Some(lang_end) = prefs_content[lang_start..].find("]") {
+                                    let language =
+                                        &prefs_content[lang_start..lang_start + lang_end];
+                                    // 处理引号和逗号
+                                    let cleaned_lang =
+                                        language.replace(['"', '['], "").trim().to_string();
+                                    if !cleaned_lang.is_empty() {
+                                        install_info.install_language = cleaned_lang;
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // 2. 尝试从Git日志中获取用户名和邮箱（需要用户开启个人信息收集）
+                if config.collect_personal_data {
+                    // 从编辑器日志中查找 git 作者信息
+                    if let Some(log_content) = find_first_line(profile, "Stored git author name") {
+                        // 从日志中提取用户名和邮箱
+                        if let Some(author_pos) = log_content.find("global state: ") {
+                            let author_start = author_pos + 14; // "global state: " 的长度
+                            let author_info = &log_content[author_start..];
+
+                            // 如果包含用户名和邮箱的格式如"李良安 <1120777912@qq.com>"
+                            if let Some(email_start) = author_info.find('<') {
+                                let username = author_info[..email_start].trim();
+                                if let Some(email_end) = author_info[email_start..].find('>') {
+                                    let email =
+                                        &author_info[email_start + 1..email_start + email_end];
+                                    if !username.is_empty() && !email.is_empty() {
+                                        // 同时显示用户名和邮箱，格式为"用户名(邮箱)"，均脱敏
+                                        install_info.install_user = format!(
+                                            "{} ({})",
+                                            redact_name(username),
+                                            redact_email(email)
+                                        );
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // 3. 如果从Git日志中没有获取到用户名，尝试从sentry/scope_v3.json获取邮箱
+                if config.collect_personal_data && install_info.install_user.is_empty() {
+                    let sentry_path = format!("{}/sentry/scope_v3.json", cursor_config_dir);
+                    if std::path::Path::new(&sentry_path).exists() {
+                        if let Ok(output) = Command::new("cat").arg(&sentry_path).output() {
+                            if let Ok(sentry_content) = String::from_utf8(output.stdout) {
+                                // 尝试获取用户邮箱
+                                if let Some(email_pos) = sentry_content.find("\"email\":") {
+                                    let email_start = email_pos + 9; // "email":" 的长度
+                                    if let Some(email_end) =
+                                        sentry_content[email_start..].find("\"")
+                                    {
+                                        let email =
+                                            &sentry_content[email_start..email_start + email_end];
+                                        if !email.is_empty() {
+                                            install_info.install_user = t_args(
+                                                "install.user_email",
+                                                &[&redact_email(email)],
+                                            );
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // 3. 尝试从languagepacks.json获取语言设置
+                if install_info.install_language.is_empty() {
+                    let lang_packs_path = format!("{}/languagepacks.json", cursor_config_dir);
+                    if std::path::Path::new(&lang_packs_path).exists() {
+                        if let Ok(output) = Command::new("cat").arg(&lang_packs_path).output() {
+                            if let Ok(lang_content) = String::from_utf8(output.stdout) {
+                                // 如果文件包含中文语言包
+                                if lang_content.contains("zh-cn") {
+                                    install_info.install_language = "zh-cn".to_string();
+                                } else if lang_content.contains("en") {
+                                    install_info.install_language = "en".to_string();
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // 4. 检查中文语言包目录是否存在
+                if install_info.install_language.is_empty() {
+                    let zh_lang_dir = format!(
+                        "{}/clp/6d6cd612ec0ae3cd32737a6f6b7ad966.zh-cn",
+                        cursor_config_dir
+                    );
+                    if std::path::Path::new(&zh_lang_dir).exists() {
+                        install_info.install_language = "zh-cn".to_string();
+                    }
+                }
+
+                // 5. 如果还是没有获取到用户信息，尝试使用应用程序路径的用户名部分
+                if config.collect_personal_data && install_info.install_user.is_empty() {
+                    if let Some(user_start) = install_info.install_path.find("/Users/") {
+                        let user_path = &install_info.install_path[user_start + 7..];
+                        if let Some(user_end) = user_path.find('/') {
+                            let username = &user_path[..user_end];
+                            if !username.is_empty() {
+                                install_info.install_user = redact_name(username);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        install_info
+    }
+}
+
+// 编辑器主程序路径：Windows 下为安装目录中的 exe，macOS 下为 .app 路径
+#[cfg_attr(target_os = "macos", allow(unused_variables))]
+pub fn get_executable_path(profile: &EditorProfile, install_info: &CursorInstallInfo) -> String {
+    if install_info.install_path.is_empty() {
+        return "".to_string();
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        PathBuf::from(&install_info.install_path)
+            .join(&profile.windows_executable)
+            .to_string_lossy()
+            .to_string()
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        install_info.install_path.clone()
+    }
+}
+
+// 安装目录下 product.json 的完整路径（第一个存在的候选）
+pub fn get_product_json_path(
+    profile: &EditorProfile,
+    install_info: &CursorInstallInfo,
+) -> Option<PathBuf> {
+    if install_info.install_path.is_empty() {
+        return None;
+    }
+    profile
+        .product_json
+        .current()
+        .iter()
+        .map(|item| PathBuf::from(&install_info.install_path).join(item))
+        .find(|path| path.exists())
+}
+
+// 安装目录下编辑器命令行工具的完整路径（第一个存在的候选）
+pub fn get_cli_path(profile: &EditorProfile, install_info: &CursorInstallInfo) -> Option<PathBuf> {
+    if install_info.install_path.is_empty() {
+        return None;
+    }
+    profile
+        .cli
+        .current()
+        .iter()
+        .map(|item| PathBuf::from(&install_info.install_path).join(item))
+        .find(|path| path.exists())
+}
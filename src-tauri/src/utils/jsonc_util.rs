@@ -0,0 +1,560 @@
+use serde::Serialize;
+use serde_json::Value;
+use std::path::Path;
+
+// JSONC（带注释、允许尾逗号的 JSON）读写工具
+// 修改时只替换目标键对应的文本片段，其余注释、顺序和缩进保持不变
+
+// 语法树节点，只记录各部分在原文中的字节位置
+enum Node {
+    Object {
+        start: usize,
+        end: usize,
+        members: Vec<Member>,
+    },
+    Array {
+        start: usize,
+        end: usize,
+    },
+    Scalar {
+        start: usize,
+        end: usize,
+    },
+}
+
+impl Node {
+    fn start(&self) -> usize {
+        match self {
+            Node::Object { start, .. } | Node::Array { start, .. } | Node::Scalar { start, .. } => {
+                *start
+            }
+        }
+    }
+
+    fn end(&self) -> usize {
+        match self {
+            Node::Object { end, .. } | Node::Array { end, .. } | Node::Scalar { end, .. } => *end,
+        }
+    }
+}
+
+struct Member {
+    key: String,
+    key_start: usize,
+    value: Node,
+    // 成员后面逗号的位置（没有逗号时为 None）
+    comma: Option<usize>,
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            bytes: text.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    fn error(&self, message: &str) -> String {
+        let line = self.text[..self.pos.min(self.text.len())]
+            .matches('\n')
+            .count()
+            + 1;
+        format!("{} (line {})", message, line)
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    // 跳过空白和注释
+    fn skip_trivia(&mut self) -> Result<(), String> {
+        while let Some(c) = self.peek() {
+            if c.is_ascii_whitespace() {
+                self.pos += 1;
+            } else if self.text[self.pos..].starts_with("//") {
+                match self.text[self.pos..].find('\n') {
+                    Some(offset) => self.pos += offset,
+                    None => self.pos = self.bytes.len(),
+                }
+            } else if self.text[self.pos..].starts_with("/*") {
+                match self.text[self.pos + 2..].find("*/") {
+                    Some(offset) => self.pos += offset + 4,
+                    None => return Err(self.error("unterminated comment")),
+                }
+            } else {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_value(&mut self) -> Result<Node, String> {
+        self.skip_trivia()?;
+        match self.peek() {
+            Some(b'{') => self.parse_object(),
+            Some(b'[') => self.parse_array(),
+            Some(b'"') => {
+                let start = self.pos;
+                self.parse_string()?;
+                Ok(Node::Scalar {
+                    start,
+                    end: self.pos,
+                })
+            }
+            Some(_) => {
+                let start = self.pos;
+                while let Some(c) = self.peek() {
+                    if c.is_ascii_alphanumeric() || c == b'-' || c == b'+' || c == b'.' {
+                        self.pos += 1;
+                    } else {
+                        break;
+                    }
+                }
+                if start == self.pos {
+                    return Err(self.error("unexpected character"));
+                }
+                Ok(Node::Scalar {
+                    start,
+                    end: self.pos,
+                })
+            }
+            None => Err(self.error("unexpected end of input")),
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String, String> {
+        let start = self.pos;
+        self.pos += 1;
+        while let Some(c) = self.peek() {
+            match c {
+                b'\\' => self.pos += 2,
+                b'"' => {
+                    self.pos += 1;
+                    return serde_json::from_str::<String>(&self.text[start..self.pos])
+                        .map_err(|e| self.error(&e.to_string()));
+                }
+                _ => self.pos += 1,
+            }
+        }
+        Err(self.error("unterminated string"))
+    }
+
+    fn parse_object(&mut self) -> Result<Node, String> {
+        let start = self.pos;
+        self.pos += 1;
+        let mut members: Vec<Member> = vec![];
+        loop {
+            self.skip_trivia()?;
+            match self.peek() {
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(Node::Object {
+                        start,
+                        end: self.pos,
+                        members,
+                    });
+                }
+                Some(b'"') => {
+                    if let Some(last) = members.last() {
+                        if last.comma.is_none() {
+                            return Err(self.error("expected ','"));
+                        }
+                    }
+                    let key_start = self.pos;
+                    let key = self.parse_string()?;
+                    self.skip_trivia()?;
+                    if self.peek() != Some(b':') {
+                        return Err(self.error("expected ':'"));
+                    }
+                    self.pos += 1;
+                    let value = self.parse_value()?;
+                    self.skip_trivia()?;
+                    let comma = if self.peek() == Some(b',') {
+                        self.pos += 1;
+                        Some(self.pos - 1)
+                    } else {
+                        None
+                    };
+                    members.push(Member {
+                        key,
+                        key_start,
+                        value,
+                        comma,
+                    });
+                }
+                _ => return Err(self.error("expected '\"' or '}'")),
+            }
+        }
+    }
+
+    fn parse_array(&mut self) -> Result<Node, String> {
+        let start = self.pos;
+        self.pos += 1;
+        let mut expect_comma = false;
+        loop {
+            self.skip_trivia()?;
+            match self.peek() {
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(Node::Array {
+                        start,
+                        end: self.pos,
+                    });
+                }
+                Some(b',') if expect_comma => {
+                    self.pos += 1;
+                    expect_comma = false;
+                }
+                Some(_) if !expect_comma => {
+                    self.parse_value()?;
+                    expect_comma = true;
+                }
+                _ => return Err(self.error("expected ',' or ']'")),
+            }
+        }
+    }
+}
+
+// 解析整个文档，空文档视为空对象
+fn parse_tree(text: &str) -> Result<Node, String> {
+    let mut parser = Parser::new(text);
+    parser.skip_trivia()?;
+    if parser.peek().is_none() {
+        return Ok(Node::Object {
+            start: 0,
+            end: 0,
+            members: vec![],
+        });
+    }
+    let root = parser.parse_value()?;
+    parser.skip_trivia()?;
+    if parser.peek().is_some() {
+        return Err(parser.error("unexpected content after document"));
+    }
+    Ok(root)
+}
+
+// 去掉注释和尾逗号，得到标准 JSON 文本
+pub fn strip(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut result = String::with_capacity(text.len());
+    let mut i = 0;
+    let mut copied = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'"' => {
+                i += 1;
+                while i < bytes.len() && bytes[i] != b'"' {
+                    i += if bytes[i] == b'\\' { 2 } else { 1 };
+                }
+                i += 1;
+            }
+            b'/' if text[i..].starts_with("//") => {
+                result.push_str(&text[copied..i]);
+                i = text[i..]
+                    .find('\n')
+                    .map(|offset| i + offset)
+                    .unwrap_or(bytes.len());
+                copied = i;
+            }
+            b'/' if text[i..].starts_with("/*") => {
+                result.push_str(&text[copied..i]);
+                i = text[i + 2..]
+                    .find("*/")
+                    .map(|offset| i + offset + 4)
+                    .unwrap_or(bytes.len());
+                result.push(' ');
+                copied = i;
+            }
+            b',' => {
+                i += 1;
+                // 向后查找下一个有效字符，如果是 } 或 ] 则丢弃这个逗号
+                let mut parser = Parser::new(text);
+                parser.pos = i;
+                let _ = parser.skip_trivia();
+                if matches!(parser.peek(), Some(b'}') | Some(b']')) {
+                    result.push_str(&text[copied..i - 1]);
+                    copied = i;
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    result.push_str(&text[copied.min(text.len())..]);
+    result
+}
+
+// 解析 JSONC 文本，空文本视为空对象
+pub fn parse(text: &str) -> Result<Value, String> {
+    if text.trim().is_empty() {
+        return Ok(Value::Object(serde_json::Map::new()));
+    }
+    // 先用语法树校验一遍，得到带行号的错误信息
+    parse_tree(text)?;
+    serde_json::from_str::<Value>(&strip(text)).map_err(|e| e.to_string())
+}
+
+// 读取 JSONC 文件，文件不存在时返回空对象
+pub fn read_file(path: &Path) -> Result<Value, String> {
+    if !path.exists() {
+        return Ok(Value::Object(serde_json::Map::new()));
+    }
+    let contents = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+    parse(&contents)
+}
+
+fn line_start(text: &str, pos: usize) -> usize {
+    text[..pos].rfind('\n').map(|i| i + 1).unwrap_or(0)
+}
+
+// pos 所在行的缩进
+fn line_indent(text: &str, pos: usize) -> &str {
+    let start = line_start(text, pos);
+    let line = &text[start..];
+    let len = line.len() - line.trim_start_matches([' ', '\t']).len();
+    &line[..len]
+}
+
+// pos 之前同一行是否只有空白
+fn starts_line(text: &str, pos: usize) -> bool {
+    text[line_start(text, pos)..pos].trim().is_empty()
+}
+
+// 从 pos 开始跳过同一行内的空白和注释，返回行尾（换行符之前）的位置
+fn end_of_line_trivia(text: &str, mut pos: usize) -> usize {
+    loop {
+        let rest = &text[pos..];
+        let trimmed = rest.trim_start_matches([' ', '\t']);
+        pos += rest.len() - trimmed.len();
+        if trimmed.starts_with("//") {
+            return pos + trimmed.find('\n').unwrap_or(trimmed.len());
+        }
+        if let Some(comment) = trimmed.strip_prefix("/*") {
+            match comment.find("*/") {
+                Some(offset) if !comment[..offset].contains('\n') => pos += offset + 4,
+                _ => return pos,
+            }
+            continue;
+        }
+        return pos;
+    }
+}
+
+// 检测文档使用的缩进单位，默认 4 个空格
+fn detect_indent_unit(text: &str) -> String {
+    for line in text.lines() {
+        let content = line.trim_start_matches([' ', '\t']);
+        let indent = &line[..line.len() - content.len()];
+        if !indent.is_empty() && !content.is_empty() {
+            return indent.to_string();
+        }
+    }
+    "    ".to_string()
+}
+
+// 按指定缩进序列化值，多行内容的后续行补上成员所在行的缩进
+fn format_value(value: &Value, unit: &str, indent: &str) -> Result<String, String> {
+    let mut buf = Vec::new();
+    let formatter = serde_json::ser::PrettyFormatter::with_indent(unit.as_bytes());
+    let mut serializer = serde_json::Serializer::with_formatter(&mut buf, formatter);
+    value
+        .serialize(&mut serializer)
+        .map_err(|e| e.to_string())?;
+    let formatted = String::from_utf8(buf).map_err(|e| e.to_string())?;
+    Ok(formatted.replace('\n', &format!("\n{}", indent)))
+}
+
+// 由剩余路径构造需要插入的值，例如 ["a", "b"] + 1 -> {"b": 1}（键 a 由调用方写入）
+fn nest_value(rest: &[&str], value: &Value) -> Value {
+    let mut nested = value.clone();
+    for key in rest.iter().rev() {
+        let mut map = serde_json::Map::new();
+        map.insert(key.to_string(), nested);
+        nested = Value::Object(map);
+    }
+    nested
+}
+
+// 在对象末尾插入一个新成员
+fn insert_member(
+    text: &str,
+    object: &Node,
+    key: &str,
+    value: &Value,
+    unit: &str,
+) -> Result<String, String> {
+    let (start, end, members) = match object {
+        Node::Object {
+            start,
+            end,
+            members,
+        } => (*start, *end, members),
+        _ => return Err("target is not an object".to_string()),
+    };
+    let key_json = serde_json::to_string(key).map_err(|e| e.to_string())?;
+
+    // 空文档：直接生成整个对象
+    if end == 0 {
+        let member_text = format!("{}{}: {}", unit, key_json, format_value(value, unit, unit)?);
+        return Ok(format!("{{\n{}\n}}\n", member_text) + text.trim_start());
+    }
+
+    match members.last() {
+        Some(last) => {
+            let indent = if starts_line(text, last.key_start) {
+                line_indent(text, last.key_start).to_string()
+            } else {
+                format!("{}{}", line_indent(text, start), unit)
+            };
+            let member_text = format!("{}: {}", key_json, format_value(value, unit, &indent)?);
+            let mut result = text.to_string();
+            match last.comma {
+                // 保留尾逗号风格：新成员后同样加逗号
+                Some(comma) => {
+                    let at = end_of_line_trivia(text, comma + 1);
+                    result.insert_str(at, &format!("\n{}{},", indent, member_text));
+                }
+                None => {
+                    let value_end = last.value.end();
+                    let at = end_of_line_trivia(text, value_end);
+                    result.insert_str(at, &format!("\n{}{}", indent, member_text));
+                    result.insert(value_end, ',');
+                }
+            }
+            Ok(result)
+        }
+        None => {
+            let indent = format!("{}{}", line_indent(text, start), unit);
+            let member_text = format!("{}: {}", key_json, format_value(value, unit, &indent)?);
+            let inner = &text[start + 1..end - 1];
+            let mut result = text.to_string();
+            if inner.trim().is_empty() {
+                // {} 或只有空白：重写对象内部
+                result.replace_range(
+                    start + 1..end - 1,
+                    &format!("\n{}{}\n{}", indent, member_text, line_indent(text, start)),
+                );
+            } else {
+                // 对象内只有注释：插在左花括号之后，注释保持原样
+                let at = end_of_line_trivia(text, start + 1);
+                result.insert_str(at, &format!("\n{}{}", indent, member_text));
+            }
+            Ok(result)
+        }
+    }
+}
+
+// 设置 path 指向的键的值，不存在时自动创建，返回修改后的文本
+pub fn set_value(text: &str, path: &[&str], value: &Value) -> Result<String, String> {
+    if path.is_empty() {
+        return Err("empty path".to_string());
+    }
+    let root = parse_tree(text)?;
+    let unit = detect_indent_unit(text);
+    let mut object = &root;
+    for (i, key) in path.iter().enumerate() {
+        let members = match object {
+            Node::Object { members, .. } => members,
+            _ => return Err(format!("'{}' is not an object", path[..i].join("."))),
+        };
+        match members.iter().find(|m| m.key == *key) {
+            Some(member) if i == path.len() - 1 => {
+                // 已存在：只替换值对应的文本
+                let indent = line_indent(text, member.key_start).to_string();
+                let mut result = text.to_string();
+                result.replace_range(
+                    member.value.start()..member.value.end(),
+                    &format_value(value, &unit, &indent)?,
+                );
+                return Ok(result);
+            }
+            Some(member) => object = &member.value,
+            None => {
+                let nested = nest_value(&path[i + 1..], value);
+                return insert_member(text, object, key, &nested, &unit);
+            }
+        }
+    }
+    unreachable!()
+}
+
+// 删除 path 指向的键，不存在时原样返回
+pub fn remove_value(text: &str, path: &[&str]) -> Result<String, String> {
+    if path.is_empty() {
+        return Err("empty path".to_string());
+    }
+    let root = parse_tree(text)?;
+    let mut object = &root;
+    for (i, key) in path.iter().enumerate() {
+        let members = match object {
+            Node::Object { members, .. } => members,
+            _ => return Ok(text.to_string()),
+        };
+        let index = match members.iter().position(|m| m.key == *key) {
+            Some(index) => index,
+            None => return Ok(text.to_string()),
+        };
+        let member = &members[index];
+        if i < path.len() - 1 {
+            object = &member.value;
+            continue;
+        }
+
+        let mut result = text.to_string();
+        let own_line = starts_line(text, member.key_start);
+        let mut remove_start = if own_line {
+            line_start(text, member.key_start)
+        } else {
+            member.key_start
+        };
+        let mut remove_end = match member.comma {
+            Some(comma) => comma + 1,
+            None => member.value.end(),
+        };
+        // 独占一行的成员连同行尾注释和换行一起删除
+        if own_line {
+            let at = end_of_line_trivia(text, remove_end);
+            if text[at..].starts_with('\n') {
+                remove_end = at + 1;
+            } else if at == text.len() {
+                remove_end = at;
+            }
+        }
+        // 删除最后一个没有逗号的成员时，需要同时删掉前一个成员后面的逗号
+        if member.comma.is_none() && index > 0 {
+            if let Some(prev_comma) = members[index - 1].comma {
+                if !own_line {
+                    remove_start = prev_comma;
+                } else {
+                    result.replace_range(remove_start..remove_end, "");
+                    result.remove(prev_comma);
+                    return Ok(result);
+                }
+            }
+        }
+        result.replace_range(remove_start..remove_end, "");
+        return Ok(result);
+    }
+    Ok(text.to_string())
+}
+
+// 修改 JSONC 文件中的单个键并写回，文件不存在时新建
+pub fn update_file(path: &Path, key_path: &[&str], value: &Value) -> Result<(), String> {
+    let contents = if path.exists() {
+        std::fs::read_to_string(path).map_err(|e| e.to_string())?
+    } else {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        String::new()
+    };
+    let updated = set_value(&contents, key_path, value)?;
+    std::fs::write(path, updated).map_err(|e| e.to_string())
+}
@@ -0,0 +1,189 @@
+use crate::model::editor_model::EditorProfile;
+use crate::model::launch_model::LaunchOptions;
+use crate::utils::i18n_util::t_args;
+use crate::utils::process_util;
+
+// --log 允许的级别
+pub const LOG_LEVELS: [&str; 7] = ["critical", "error", "warn", "info", "debug", "trace", "off"];
+
+// 实际启动进程的方式，测试中替换为记录参数的假启动器
+pub trait Launcher {
+    fn launch(&self, executable: &str, args: &[String], env: &[(String, String)]) -> bool;
+}
+
+// 通过系统命令启动编辑器
+pub struct SystemLauncher<'a> {
+    pub profile: &'a EditorProfile,
+}
+
+impl Launcher for SystemLauncher<'_> {
+    fn launch(&self, executable: &str, args: &[String], env: &[(String, String)]) -> bool {
+        process_util::launch(self.profile, executable, args, env)
+    }
+}
+
+// 将启动选项转换为编辑器命令行参数，各平台共用
+pub fn build_args(options: &LaunchOptions) -> Result<Vec<String>, String> {
+    let mut args = vec![];
+    if options.new_window {
+        args.push("--new-window".to_string());
+    }
+    if options.disable_extensions {
+        args.push("--disable-extensions".to_string());
+    }
+    if options.verbose {
+        args.push("--verbose".to_string());
+    }
+    if !options.log_level.is_empty() {
+        let level = options.log_level.to_lowercase();
+        if !LOG_LEVELS.contains(&level.as_str()) {
+            return Err(t_args("error.launch_log_level", &[&options.log_level]));
+        }
+        args.push("--log".to_string());
+        args.push(level);
+    }
+    if !options.user_data_dir.is_empty() {
+        args.push("--user-data-dir".to_string());
+        args.push(options.user_data_dir.clone());
+    }
+    for path in options.folders.iter().chain(&options.files) {
+        // 以 - 开头的路径会被编辑器当作选项解析
+        if path.is_empty() || path.starts_with('-') {
+            return Err(t_args("error.launch_path", &[path]));
+        }
+        args.push(path.clone());
+    }
+    Ok(args)
+}
+
+// 检查环境变量名，返回 (名, 值) 列表
+pub fn build_env(options: &LaunchOptions) -> Result<Vec<(String, String)>, String> {
+    options
+        .env
+        .iter()
+        .map(|(key, value)| {
+            if key.is_empty() || key.contains(['=', '\0']) || value.contains('\0') {
+                Err(t_args("error.launch_env", &[key]))
+            } else {
+                Ok((key.clone(), value.clone()))
+            }
+        })
+        .collect()
+}
+
+// 按选项启动编辑器
+pub fn launch_with(
+    launcher: &dyn Launcher,
+    executable: &str,
+    options: &LaunchOptions,
+) -> Result<bool, String> {
+    let args = build_args(options)?;
+    let env = build_env(options)?;
+    Ok(launcher.launch(executable, &args, &env))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    struct LaunchCall {
+        executable: String,
+        args: Vec<String>,
+        env: Vec<(String, String)>,
+    }
+
+    // 记录每次调用的参数，不真正启动进程
+    #[derive(Default)]
+    struct FakeLauncher {
+        calls: RefCell<Vec<LaunchCall>>,
+    }
+
+    impl Launcher for FakeLauncher {
+        fn launch(&self, executable: &str, args: &[String], env: &[(String, String)]) -> bool {
+            self.calls.borrow_mut().push(LaunchCall {
+                executable: executable.to_string(),
+                args: args.to_vec(),
+                env: env.to_vec(),
+            });
+            true
+        }
+    }
+
+    #[test]
+    fn default_options_launch_without_arguments() {
+        let launcher = FakeLauncher::default();
+        let launched = launch_with(&launcher, "cursor", &LaunchOptions::default()).unwrap();
+        assert!(launched);
+        let calls = launcher.calls.borrow();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].executable, "cursor");
+        assert!(calls[0].args.is_empty());
+        assert!(calls[0].env.is_empty());
+    }
+
+    #[test]
+    fn options_map_to_flags_before_paths() {
+        let options = LaunchOptions {
+            folders: vec!["/work/project".to_string()],
+            files: vec!["/work/notes.md".to_string()],
+            new_window: true,
+            disable_extensions: true,
+            verbose: true,
+            log_level: "Debug".to_string(),
+            user_data_dir: "/tmp/profile".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            build_args(&options).unwrap(),
+            [
+                "--new-window",
+                "--disable-extensions",
+                "--verbose",
+                "--log",
+                "debug",
+                "--user-data-dir",
+                "/tmp/profile",
+                "/work/project",
+                "/work/notes.md",
+            ]
+        );
+    }
+
+    #[test]
+    fn env_vars_are_passed_to_launcher() {
+        let launcher = FakeLauncher::default();
+        let mut options = LaunchOptions::default();
+        options
+            .env
+            .insert("ELECTRON_ENABLE_LOGGING".to_string(), "1".to_string());
+        launch_with(&launcher, "cursor", &options).unwrap();
+        assert_eq!(
+            launcher.calls.borrow()[0].env,
+            [("ELECTRON_ENABLE_LOGGING".to_string(), "1".to_string())]
+        );
+    }
+
+    #[test]
+    fn invalid_options_do_not_launch() {
+        let launcher = FakeLauncher::default();
+        let invalid = [
+            LaunchOptions {
+                log_level: "loud".to_string(),
+                ..Default::default()
+            },
+            LaunchOptions {
+                files: vec!["--inspect".to_string()],
+                ..Default::default()
+            },
+            LaunchOptions {
+                env: [("A=B".to_string(), "1".to_string())].into_iter().collect(),
+                ..Default::default()
+            },
+        ];
+        for options in &invalid {
+            assert!(launch_with(&launcher, "cursor", options).is_err());
+        }
+        assert!(launcher.calls.borrow().is_empty());
+    }
+}
@@ -0,0 +1,51 @@
+use crate::utils::config_util::get_app_data_dir;
+use crate::utils::redact_util::redact_text;
+use crate::utils::time_util::{format_utc, now_secs};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+
+const LOG_FILE_NAME: &str = "cursor-reset.log";
+
+pub fn get_log_dir() -> PathBuf {
+    let dir = get_app_data_dir().join("logs");
+    let _ = std::fs::create_dir_all(&dir);
+    dir
+}
+
+pub fn get_log_path() -> PathBuf {
+    get_log_dir().join(LOG_FILE_NAME)
+}
+
+// 追加一行本工具日志，写入前统一脱敏
+pub fn write_log(level: &str, message: &str) {
+    let line = format!(
+        "{} [{}] {}\n",
+        format_utc(now_secs()),
+        level,
+        redact_text(message)
+    );
+    if let Ok(mut file) = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(get_log_path())
+    {
+        let _ = file.write_all(line.as_bytes());
+    }
+}
+
+pub fn info(message: &str) {
+    write_log("info", message);
+}
+
+pub fn error(message: &str) {
+    write_log("error", message);
+}
+
+// 读取最近的若干行日志
+pub fn read_recent_logs(max_lines: usize) -> Vec<String> {
+    let contents = std::fs::read_to_string(get_log_path()).unwrap_or_default();
+    let lines: Vec<String> = contents.lines().map(|line| line.to_string()).collect();
+    let start = lines.len().saturating_sub(max_lines);
+    lines[start..].to_vec()
+}
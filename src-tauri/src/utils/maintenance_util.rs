@@ -0,0 +1,76 @@
+use crate::model::editor_model::EditorProfile;
+use crate::model::maintenance_model::{DbMaintenanceResult, MaintenanceReport};
+use crate::utils::backup_util::create_backup_dir;
+use crate::utils::state_db_util::{
+    copy_db_into, db_size, get_global_state_db_path, integrity_check, vacuum,
+};
+use crate::utils::workspace_util::get_workspace_storage_dir;
+use rusqlite::Connection;
+use std::path::{Path, PathBuf};
+
+const STATE_DB_NAME: &str = "state.vscdb";
+
+// 全局和各工作区的 state.vscdb，返回 (范围, 路径)
+pub fn find_state_dbs(profile: &EditorProfile) -> Vec<(String, PathBuf)> {
+    let mut dbs = vec![];
+    if let Some(global) = get_global_state_db_path(profile) {
+        dbs.push(("global".to_string(), global));
+    }
+    if let Ok(storage_dir) = get_workspace_storage_dir(profile) {
+        for entry in std::fs::read_dir(storage_dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+        {
+            let db_path = entry.path().join(STATE_DB_NAME);
+            if db_path.is_file() {
+                dbs.push((entry.file_name().to_string_lossy().to_string(), db_path));
+            }
+        }
+    }
+    dbs
+}
+
+fn maintain_db(scope: &str, db_path: &Path) -> DbMaintenanceResult {
+    let mut result = DbMaintenanceResult {
+        scope: scope.to_string(),
+        path: db_path.to_string_lossy().to_string(),
+        size_before: db_size(db_path),
+        ..Default::default()
+    };
+    let outcome = Connection::open(db_path)
+        .map_err(|e| e.to_string())
+        .and_then(|conn| {
+            result.integrity_errors = integrity_check(&conn)?;
+            result.integrity_ok = result.integrity_errors.is_empty();
+            if result.integrity_ok {
+                vacuum(&conn)?;
+                result.vacuumed = true;
+            }
+            Ok(())
+        });
+    if let Err(e) = outcome {
+        result.error = e;
+    }
+    result.size_after = db_size(db_path);
+    result
+}
+
+// 备份全部 state.vscdb 后逐个做完整性检查和 VACUUM，调用方需确保编辑器未运行
+pub fn maintain_state_dbs(profile: &EditorProfile) -> Result<MaintenanceReport, String> {
+    let dbs = find_state_dbs(profile);
+    let backup_dir = create_backup_dir("state-maintenance")?;
+    for (scope, db_path) in &dbs {
+        copy_db_into(db_path, &backup_dir.join(scope))?;
+    }
+    let databases: Vec<DbMaintenanceResult> = dbs
+        .iter()
+        .map(|(scope, db_path)| maintain_db(scope, db_path))
+        .collect();
+    Ok(MaintenanceReport {
+        backup_path: backup_dir.to_string_lossy().to_string(),
+        size_before: databases.iter().map(|db| db.size_before).sum(),
+        size_after: databases.iter().map(|db| db.size_after).sum(),
+        databases,
+    })
+}
@@ -1 +1,32 @@
-pub mod cursor_util;
\ No newline at end of file
+pub mod backup_util;
+pub mod config_util;
+pub mod crash_util;
+pub mod cursor_util;
+pub mod diagnostics_util;
+pub mod disk_util;
+pub mod doctor_util;
+pub mod editor_log_util;
+pub mod editor_util;
+pub mod extension_util;
+pub mod fs_util;
+pub mod git_util;
+pub mod history_util;
+pub mod hook_util;
+pub mod hot_exit_util;
+pub mod i18n_util;
+pub mod install_util;
+pub mod jsonc_util;
+pub mod launch_util;
+pub mod log_util;
+pub mod maintenance_util;
+pub mod process_util;
+pub mod profile_util;
+pub mod recent_util;
+pub mod redact_util;
+pub mod report_util;
+pub mod settings_backup_util;
+pub mod state_db_util;
+pub mod storage_util;
+pub mod telemetry_util;
+pub mod time_util;
+pub mod workspace_util;
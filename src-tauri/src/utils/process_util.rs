@@ -0,0 +1,162 @@
+use crate::model::editor_model::EditorProfile;
+use std::process::Command;
+
+#[cfg(target_os = "windows")]
+use std::os::windows::process::CommandExt;
+
+// Windows 下启动子进程时不弹出控制台窗口
+#[cfg(target_os = "windows")]
+pub const CREATE_NO_WINDOW: u32 = 0x08000000;
+
+// 检测编辑器是否正在运行
+pub fn is_running(profile: &EditorProfile) -> bool {
+    for process_name in profile.process_names.current() {
+        #[cfg(target_os = "windows")]
+        {
+            // 在 Windows 上检查进程
+            if let Ok(output) = Command::new("tasklist")
+                .args(["/FI", &format!("IMAGENAME eq {}", process_name), "/NH"])
+                .creation_flags(CREATE_NO_WINDOW)
+                .output()
+            {
+                if let Ok(stdout) = String::from_utf8(output.stdout) {
+                    if stdout.contains(process_name.as_str()) {
+                        return true;
+                    }
+                }
+            }
+        }
+
+        #[cfg(target_os = "macos")]
+        {
+            // 在 macOS 上检查进程
+            if let Ok(output) = Command::new("pgrep").args(["-x", process_name]).output() {
+                if !output.stdout.is_empty() {
+                    return true;
+                }
+            }
+        }
+    }
+    false
+}
+
+// 结束编辑器的所有进程
+pub fn kill(profile: &EditorProfile) {
+    for process_name in profile.process_names.current() {
+        #[cfg(target_os = "windows")]
+        let _ = Command::new("taskkill")
+            .args(["/F", "/IM", process_name])
+            .creation_flags(CREATE_NO_WINDOW)
+            .output();
+
+        #[cfg(target_os = "macos")]
+        let _ = Command::new("killall").args([process_name]).output();
+    }
+
+    // 等待一秒确保进程完全关闭
+    #[cfg(target_os = "macos")]
+    std::thread::sleep(std::time::Duration::from_secs(1));
+}
+
+// 启动编辑器，executable 为 install_util::get_executable_path 的返回值，
+// args 为传给编辑器的命令行参数，env 为额外的环境变量
+#[cfg_attr(target_os = "windows", allow(unused_variables))]
+pub fn launch(
+    profile: &EditorProfile,
+    executable: &str,
+    args: &[String],
+    env: &[(String, String)],
+) -> bool {
+    #[cfg(target_os = "windows")]
+    {
+        Command::new(executable)
+            .args(args)
+            .envs(env.iter().cloned())
+            .creation_flags(CREATE_NO_WINDOW)
+            .spawn()
+            .is_ok()
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        // 如果路径以 .app 结尾，使用 open 命令，否则按应用名打开
+        let app = if executable.ends_with(".app") {
+            executable
+        } else {
+            profile.name.as_str()
+        };
+        let mut command = Command::new("open");
+        // 带参数启动时需要新开一个实例，否则参数会被已运行的实例忽略
+        if !args.is_empty() || !env.is_empty() {
+            command.arg("-n");
+        }
+        command.args(["-a", app]);
+        for (key, value) in env {
+            command.arg("--env").arg(format!("{}={}", key, value));
+        }
+        if !args.is_empty() {
+            command.arg("--args").args(args);
+        }
+        command
+            .output()
+            .map(|output| output.status.success())
+            .unwrap_or(false)
+    }
+}
+
+// 主窗口已经关闭但仍在后台运行的编辑器进程，返回 "PID 名称" 列表
+pub fn find_stale_processes(profile: &EditorProfile) -> Vec<String> {
+    let mut stale = vec![];
+    for process_name in profile.process_names.current() {
+        #[cfg(target_os = "windows")]
+        {
+            // 没有主窗口句柄的进程；只要有一个进程带窗口，说明编辑器正常运行
+            let name = process_name.trim_end_matches(".exe");
+            let script = format!(
+                "Get-Process -Name '{}' -ErrorAction SilentlyContinue | ForEach-Object {{ \"$($_.Id) $($_.MainWindowHandle)\" }}",
+                name
+            );
+            if let Ok(output) = Command::new("powershell")
+                .args(["-NoProfile", "-Command", &script])
+                .creation_flags(CREATE_NO_WINDOW)
+                .output()
+            {
+                let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+                let processes: Vec<(&str, &str)> = stdout
+                    .lines()
+                    .filter_map(|line| line.trim().split_once(' '))
+                    .collect();
+                if !processes.is_empty() && processes.iter().all(|(_, handle)| *handle == "0") {
+                    stale.extend(
+                        processes
+                            .iter()
+                            .map(|(pid, _)| format!("{} {}", pid, process_name)),
+                    );
+                }
+            }
+        }
+
+        #[cfg(target_os = "macos")]
+        {
+            // 主进程已退出但 Helper 进程仍在运行
+            let main_running = Command::new("pgrep")
+                .args(["-x", process_name])
+                .output()
+                .map(|output| !output.stdout.is_empty())
+                .unwrap_or(false);
+            if !main_running {
+                if let Ok(output) = Command::new("pgrep")
+                    .args(["-fl", &format!("{} Helper", process_name)])
+                    .output()
+                {
+                    stale.extend(
+                        String::from_utf8_lossy(&output.stdout)
+                            .lines()
+                            .map(|line| line.to_string()),
+                    );
+                }
+            }
+        }
+    }
+    stale
+}
@@ -0,0 +1,135 @@
+use crate::model::cursor_model::MaskMode;
+use crate::model::editor_model::EditorProfile;
+use crate::model::profile_model::IsolatedProfile;
+use crate::utils::config_util::get_app_data_dir;
+use crate::utils::cursor_util::read_device_info;
+use crate::utils::fs_util::{copy_dir_all, dir_size};
+use crate::utils::i18n_util::t_args;
+use crate::utils::time_util::{format_utc, now_secs};
+use std::path::PathBuf;
+
+const USER_DATA_DIR: &str = "data";
+const EXTENSIONS_DIR: &str = "extensions";
+// 记录创建时间的文件
+const CREATED_FILE: &str = "created_at";
+
+// 隔离配置根目录：应用数据目录/profiles/<编辑器>
+pub fn get_profiles_root(profile: &EditorProfile) -> PathBuf {
+    get_app_data_dir().join("profiles").join(&profile.id)
+}
+
+// 配置名会作为目录名使用，不允许路径分隔符和 Windows 保留字符
+fn validate_name(name: &str) -> Result<(), String> {
+    let invalid = name.trim().is_empty()
+        || name.starts_with('.')
+        || name.contains(['/', '\\', ':', '*', '?', '"', '<', '>', '|']);
+    if invalid {
+        return Err(t_args("error.profile_name", &[name]));
+    }
+    Ok(())
+}
+
+fn get_profile_dir(profile: &EditorProfile, name: &str) -> Result<PathBuf, String> {
+    validate_name(name)?;
+    Ok(get_profiles_root(profile).join(name))
+}
+
+// 已存在的配置目录
+fn get_existing_profile_dir(profile: &EditorProfile, name: &str) -> Result<PathBuf, String> {
+    let dir = get_profile_dir(profile, name)?;
+    if !dir.is_dir() {
+        return Err(t_args("error.profile_not_found", &[name]));
+    }
+    Ok(dir)
+}
+
+fn read_profile(profile: &EditorProfile, name: &str, mask: MaskMode) -> Option<IsolatedProfile> {
+    let dir = get_profile_dir(profile, name).ok()?;
+    let user_data_dir = dir.join(USER_DATA_DIR);
+    let storage_path = user_data_dir
+        .join("User")
+        .join("globalStorage")
+        .join("storage.json");
+    let device_info = if storage_path.exists() {
+        Some(
+            read_device_info(
+                storage_path.to_string_lossy().to_string(),
+                &profile.telemetry_keys,
+            )
+            .masked(mask),
+        )
+    } else {
+        None
+    };
+    Some(IsolatedProfile {
+        name: name.to_string(),
+        editor: profile.id.clone(),
+        path: dir.to_string_lossy().to_string(),
+        user_data_dir: user_data_dir.to_string_lossy().to_string(),
+        extensions_dir: dir.join(EXTENSIONS_DIR).to_string_lossy().to_string(),
+        size: dir_size(&dir),
+        created_at: std::fs::read_to_string(dir.join(CREATED_FILE)).unwrap_or_default(),
+        device_info,
+    })
+}
+
+pub fn list_profiles(profile: &EditorProfile, mask: MaskMode) -> Vec<IsolatedProfile> {
+    let mut profiles: Vec<IsolatedProfile> = std::fs::read_dir(get_profiles_root(profile))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| read_profile(profile, &entry.file_name().to_string_lossy(), mask))
+        .collect();
+    profiles.sort_by(|a, b| a.name.cmp(&b.name));
+    profiles
+}
+
+pub fn create_profile(
+    profile: &EditorProfile,
+    name: &str,
+    mask: MaskMode,
+) -> Result<IsolatedProfile, String> {
+    let dir = get_profile_dir(profile, name)?;
+    if dir.exists() {
+        return Err(t_args("error.profile_exists", &[name]));
+    }
+    for sub_dir in [USER_DATA_DIR, EXTENSIONS_DIR] {
+        std::fs::create_dir_all(dir.join(sub_dir)).map_err(|e| e.to_string())?;
+    }
+    std::fs::write(dir.join(CREATED_FILE), format_utc(now_secs())).map_err(|e| e.to_string())?;
+    read_profile(profile, name, mask).ok_or_else(|| t_args("error.profile_not_found", &[name]))
+}
+
+// 复制已有配置（包括数据和扩展）为新配置
+pub fn clone_profile(
+    profile: &EditorProfile,
+    source: &str,
+    name: &str,
+    mask: MaskMode,
+) -> Result<IsolatedProfile, String> {
+    let source_dir = get_existing_profile_dir(profile, source)?;
+    let dir = get_profile_dir(profile, name)?;
+    if dir.exists() {
+        return Err(t_args("error.profile_exists", &[name]));
+    }
+    copy_dir_all(&source_dir, &dir).map_err(|e| e.to_string())?;
+    std::fs::write(dir.join(CREATED_FILE), format_utc(now_secs())).map_err(|e| e.to_string())?;
+    read_profile(profile, name, mask).ok_or_else(|| t_args("error.profile_not_found", &[name]))
+}
+
+pub fn delete_profile(profile: &EditorProfile, name: &str) -> Result<(), String> {
+    let dir = get_existing_profile_dir(profile, name)?;
+    std::fs::remove_dir_all(dir).map_err(|e| e.to_string())
+}
+
+// 启动编辑器时使用的参数
+pub fn launch_args(profile: &EditorProfile, name: &str) -> Result<Vec<String>, String> {
+    let dir = get_existing_profile_dir(profile, name)?;
+    Ok(vec![
+        "--user-data-dir".to_string(),
+        dir.join(USER_DATA_DIR).to_string_lossy().to_string(),
+        "--extensions-dir".to_string(),
+        dir.join(EXTENSIONS_DIR).to_string_lossy().to_string(),
+    ])
+}
@@ -0,0 +1,106 @@
+use crate::model::editor_model::EditorProfile;
+use crate::model::recent_model::{RecentEntry, RecentUpdateResult};
+use crate::utils::fs_util::file_uri_to_path;
+use crate::utils::i18n_util::t;
+use crate::utils::state_db_util::{backup_db, get_global_state_db_path, read_item, write_item};
+use serde_json::{json, Value};
+use std::path::PathBuf;
+
+// 全局状态库中保存最近打开列表的键
+const RECENT_KEY: &str = "history.recentlyOpenedPathsList";
+
+fn get_db_path(profile: &EditorProfile) -> Result<PathBuf, String> {
+    get_global_state_db_path(profile).ok_or_else(|| t("error.state_db_not_found"))
+}
+
+// 读取原始条目列表，保留未识别的字段以便原样写回
+fn read_raw_entries(profile: &EditorProfile) -> Result<Vec<Value>, String> {
+    let value = read_item(&get_db_path(profile)?, RECENT_KEY)?;
+    let json = value
+        .and_then(|value| serde_json::from_str::<Value>(&value).ok())
+        .unwrap_or(Value::Null);
+    Ok(json
+        .get("entries")
+        .and_then(|entries| entries.as_array())
+        .cloned()
+        .unwrap_or_default())
+}
+
+fn to_entry(raw: &Value) -> Option<RecentEntry> {
+    let str_field = |value: Option<&Value>| {
+        value
+            .and_then(|value| value.as_str())
+            .unwrap_or_default()
+            .to_string()
+    };
+    let (kind, uri) = if let Some(uri) = raw.get("folderUri") {
+        ("folder", str_field(Some(uri)))
+    } else if let Some(workspace) = raw.get("workspace") {
+        ("workspace", str_field(workspace.get("configPath")))
+    } else if let Some(uri) = raw.get("fileUri") {
+        ("file", str_field(Some(uri)))
+    } else {
+        return None;
+    };
+    let local_path = file_uri_to_path(&uri);
+    Some(RecentEntry {
+        kind: kind.to_string(),
+        label: str_field(raw.get("label")),
+        remote_authority: str_field(raw.get("remoteAuthority")),
+        exists: local_path.as_ref().is_none_or(|path| path.exists()),
+        local_path: local_path
+            .map(|path| path.to_string_lossy().to_string())
+            .unwrap_or_default(),
+        uri,
+    })
+}
+
+pub fn list_recent(profile: &EditorProfile) -> Result<Vec<RecentEntry>, String> {
+    Ok(read_raw_entries(profile)?
+        .iter()
+        .filter_map(to_entry)
+        .collect())
+}
+
+// 保留 keep 返回 true 的条目，备份后写回，调用方需确保编辑器未运行
+fn retain_entries(
+    profile: &EditorProfile,
+    keep: impl Fn(&RecentEntry) -> bool,
+) -> Result<RecentUpdateResult, String> {
+    let db_path = get_db_path(profile)?;
+    let raw_entries = read_raw_entries(profile)?;
+    let total = raw_entries.len();
+    let kept: Vec<Value> = raw_entries
+        .into_iter()
+        .filter(|raw| to_entry(raw).is_none_or(|entry| keep(&entry)))
+        .collect();
+    let removed = total - kept.len();
+    let backup_path = backup_db(&db_path, "state")?;
+    if removed > 0 {
+        let value = json!({ "entries": kept }).to_string();
+        write_item(&db_path, RECENT_KEY, &value)?;
+    }
+    Ok(RecentUpdateResult {
+        removed,
+        backup_path,
+        entries: kept.iter().filter_map(to_entry).collect(),
+    })
+}
+
+// 删除指定 URI 的条目
+pub fn remove_recent(
+    profile: &EditorProfile,
+    uris: &[String],
+) -> Result<RecentUpdateResult, String> {
+    retain_entries(profile, |entry| !uris.contains(&entry.uri))
+}
+
+// 删除本地路径已不存在的条目
+pub fn remove_missing_recent(profile: &EditorProfile) -> Result<RecentUpdateResult, String> {
+    retain_entries(profile, |entry| entry.exists)
+}
+
+// 清空最近打开列表
+pub fn clear_recent(profile: &EditorProfile) -> Result<RecentUpdateResult, String> {
+    retain_entries(profile, |_| false)
+}
@@ -0,0 +1,186 @@
+use crate::model::cursor_model::CursorInstallInfo;
+
+// 邮箱脱敏：john.doe@example.com -> j***@example.com
+pub fn redact_email(email: &str) -> String {
+    match email.split_once('@') {
+        Some((local, domain)) => match local.chars().next() {
+            Some(first) => format!("{}***@{}", first, domain),
+            None => format!("***@{}", domain),
+        },
+        None => redact_name(email),
+    }
+}
+
+// 姓名 / 用户名脱敏：只保留第一个字符
+pub fn redact_name(name: &str) -> String {
+    let name = name.trim();
+    match name.chars().next() {
+        Some(first) => format!("{}***", first),
+        None => "".to_string(),
+    }
+}
+
+fn is_email_local_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "._%+-".contains(c)
+}
+
+fn is_email_domain_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || ".-".contains(c)
+}
+
+// 将任意文本中出现的邮箱全部脱敏
+pub fn redact_emails_in_text(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut result = String::with_capacity(text.len());
+    let mut copied = 0;
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i] != '@' {
+            i += 1;
+            continue;
+        }
+        let mut start = i;
+        while start > copied && is_email_local_char(chars[start - 1]) {
+            start -= 1;
+        }
+        let mut end = i + 1;
+        while end < chars.len() && is_email_domain_char(chars[end]) {
+            end += 1;
+        }
+        // 去掉域名末尾的句点，例如句子结尾的 "xx@qq.com."
+        while end > i + 1 && chars[end - 1] == '.' {
+            end -= 1;
+        }
+        let domain: String = chars[i + 1..end].iter().collect();
+        if start < i && domain.contains('.') {
+            let email: String = chars[start..end].iter().collect();
+            result.extend(&chars[copied..start]);
+            result.push_str(&redact_email(&email));
+            copied = end;
+        }
+        i = end.max(i + 1);
+    }
+    result.extend(&chars[copied..]);
+    result
+}
+
+// 路径脱敏：将 C:\Users\john\... 或 /Users/john/... 中的用户名替换掉
+pub fn redact_path(path: &str) -> String {
+    for marker in ["\\Users\\", "/Users/", "\\users\\"] {
+        if let Some(pos) = path.find(marker) {
+            let user_start = pos + marker.len();
+            let rest = &path[user_start..];
+            let user_end = rest.find(['\\', '/']).unwrap_or(rest.len());
+            let username = &rest[..user_end];
+            if username.is_empty() {
+                return path.to_string();
+            }
+            return format!(
+                "{}{}{}",
+                &path[..user_start],
+                redact_name(username),
+                &rest[user_end..]
+            );
+        }
+    }
+    path.to_string()
+}
+
+// 通用文本脱敏：用于日志和导出内容
+pub fn redact_text(text: &str) -> String {
+    let text = redact_emails_in_text(text);
+    text.lines()
+        .map(redact_path)
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+// 返回给前端 / 导出前对安装信息做脱敏
+pub fn redact_install_info(info: &CursorInstallInfo) -> CursorInstallInfo {
+    CursorInstallInfo::new(
+        redact_path(&info.install_path),
+        info.install_language.clone(),
+        info.install_version.clone(),
+        redact_emails_in_text(&info.install_user),
+    )
+}
+
+// 键名中出现这些词时，整个值视为敏感信息
+const SECRET_KEY_WORDS: [&str; 11] = [
+    "token",
+    "secret",
+    "password",
+    "passwd",
+    "credential",
+    "authorization",
+    "apikey",
+    "api_key",
+    "cookie",
+    "jwt",
+    "privatekey",
+];
+// 长度达到该值的无空白随机串视为令牌
+const SECRET_MIN_LEN: usize = 32;
+
+pub fn is_secret_key(key: &str) -> bool {
+    let key = key.to_lowercase();
+    SECRET_KEY_WORDS.iter().any(|word| key.contains(word))
+}
+
+// 看起来像令牌或凭据的字符串：JWT，或较长的、字母数字混合的无空白字符串
+pub fn looks_like_secret(value: &str) -> bool {
+    let value = value.trim();
+    if value.starts_with("eyJ") && value.matches('.').count() == 2 {
+        return true;
+    }
+    value.len() >= SECRET_MIN_LEN
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_.+/=".contains(c))
+        && value.chars().any(|c| c.is_ascii_digit())
+        && value.chars().any(|c| c.is_ascii_alphabetic())
+}
+
+pub fn redact_secret(value: &str) -> String {
+    format!("[redacted {} chars]", value.chars().count())
+}
+
+fn redact_json_secrets(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(obj) => {
+            for (key, item) in obj.iter_mut() {
+                if is_secret_key(key) && !item.is_null() {
+                    *item = serde_json::Value::String(redact_secret(&item.to_string()));
+                } else {
+                    redact_json_secrets(item);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(redact_json_secrets),
+        serde_json::Value::String(text) => {
+            *text = if looks_like_secret(text) {
+                redact_secret(text)
+            } else {
+                redact_text(text)
+            };
+        }
+        _ => {}
+    }
+}
+
+// 对键值对做敏感信息脱敏：键名敏感时整体遮盖，JSON 值逐项处理，其它文本按令牌规则和通用规则处理
+pub fn redact_secrets(key: &str, value: &str) -> String {
+    if is_secret_key(key) {
+        return redact_secret(value);
+    }
+    if let Ok(mut json) = serde_json::from_str::<serde_json::Value>(value) {
+        if json.is_object() || json.is_array() {
+            redact_json_secrets(&mut json);
+            return serde_json::to_string_pretty(&json).unwrap_or_default();
+        }
+    }
+    if looks_like_secret(value) {
+        return redact_secret(value);
+    }
+    redact_text(value)
+}
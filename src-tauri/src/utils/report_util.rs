@@ -0,0 +1,256 @@
+use crate::model::cursor_model::MaskMode;
+use crate::model::doctor_model::CheckStatus;
+use crate::model::editor_model::EditorProfile;
+use crate::model::report_model::{Report, ReportFormat};
+use crate::utils::config_util::get_app_data_dir;
+use crate::utils::cursor_util::{get_package_path, read_device_info};
+use crate::utils::doctor_util::run_checks;
+use crate::utils::history_util::load_history;
+use crate::utils::i18n_util::t;
+use crate::utils::install_util::find_install_info;
+use crate::utils::process_util::is_running;
+use crate::utils::redact_util::{redact_install_info, redact_text};
+use crate::utils::time_util::{file_stamp, format_utc, now_secs};
+use std::path::{Path, PathBuf};
+
+// 报告中包含的历史记录条数
+const REPORT_HISTORY: usize = 20;
+
+// 报告中的一个表格
+struct Section {
+    title: String,
+    header: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+pub fn build_report(profile: &EditorProfile, mask: MaskMode) -> Report {
+    let package_path = get_package_path(profile);
+    let device_info = if package_path.is_empty() {
+        None
+    } else {
+        Some(read_device_info(package_path, &profile.telemetry_keys).masked(mask))
+    };
+    let mut history: Vec<_> = load_history()
+        .into_iter()
+        .filter(|entry| entry.editor == profile.id)
+        .collect();
+    history.reverse();
+    history.truncate(REPORT_HISTORY);
+    Report {
+        generated_at: format_utc(now_secs()),
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        editor: profile.id.clone(),
+        os: std::env::consts::OS.to_string(),
+        arch: std::env::consts::ARCH.to_string(),
+        cursor_running: is_running(profile),
+        install_info: redact_install_info(&find_install_info(profile)),
+        device_info,
+        checks: run_checks(profile),
+        history,
+    }
+}
+
+fn status_text(status: CheckStatus) -> &'static str {
+    match status {
+        CheckStatus::Pass => "PASS",
+        CheckStatus::Warn => "WARN",
+        CheckStatus::Fail => "FAIL",
+    }
+}
+
+fn pairs(rows: &[(&str, &str)]) -> Vec<Vec<String>> {
+    rows.iter()
+        .map(|(key, value)| vec![key.to_string(), value.to_string()])
+        .collect()
+}
+
+fn sections(report: &Report) -> Vec<Section> {
+    let field_header = vec![t("report.field"), t("report.value")];
+    let running = report.cursor_running.to_string();
+    let mut sections = vec![Section {
+        title: t("report.summary"),
+        header: field_header.clone(),
+        rows: pairs(&[
+            ("generated_at", &report.generated_at),
+            ("app_version", &report.app_version),
+            ("editor", &report.editor),
+            ("os", &format!("{} {}", report.os, report.arch)),
+            ("cursor_running", &running),
+        ]),
+    }];
+    let install = &report.install_info;
+    sections.push(Section {
+        title: t("report.install"),
+        header: field_header.clone(),
+        rows: pairs(&[
+            ("install_path", &install.install_path),
+            ("install_version", &install.install_version),
+            ("install_language", &install.install_language),
+            ("install_user", &install.install_user),
+        ]),
+    });
+    if let Some(device) = &report.device_info {
+        sections.push(Section {
+            title: t("report.device"),
+            header: field_header,
+            rows: pairs(&[
+                ("macMachineId", &device.mac_machine_id),
+                ("machineId", &device.machine_id),
+                ("sqmId", &device.sqm_id),
+                ("devDeviceId", &device.dev_device_id),
+            ]),
+        });
+    }
+    sections.push(Section {
+        title: t("report.checks"),
+        header: vec![
+            t("report.status"),
+            t("report.check"),
+            t("report.detail"),
+            t("report.remedy"),
+        ],
+        rows: report
+            .checks
+            .iter()
+            .map(|check| {
+                vec![
+                    status_text(check.status).to_string(),
+                    check.title.clone(),
+                    check.detail.clone(),
+                    check.remedy.clone(),
+                ]
+            })
+            .collect(),
+    });
+    sections.push(Section {
+        title: t("report.history"),
+        header: vec![
+            t("report.time"),
+            t("report.action"),
+            t("report.status"),
+            t("report.detail"),
+        ],
+        rows: report
+            .history
+            .iter()
+            .map(|entry| {
+                vec![
+                    entry.time.clone(),
+                    entry.action.clone(),
+                    if entry.success { "OK" } else { "FAILED" }.to_string(),
+                    redact_text(&entry.detail),
+                ]
+            })
+            .collect(),
+    });
+    sections
+}
+
+fn escape_markdown(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', "<br>")
+}
+
+fn render_markdown(report: &Report) -> String {
+    let mut out = format!("# {}\n", t("report.title"));
+    for section in sections(report) {
+        out.push_str(&format!("\n## {}\n\n", section.title));
+        if section.rows.is_empty() {
+            out.push_str(&format!("{}\n", t("report.empty")));
+            continue;
+        }
+        out.push_str(&format!("| {} |\n", section.header.join(" | ")));
+        out.push_str(&format!("|{}\n", " --- |".repeat(section.header.len())));
+        for row in &section.rows {
+            let cells: Vec<String> = row.iter().map(|cell| escape_markdown(cell)).collect();
+            out.push_str(&format!("| {} |\n", cells.join(" | ")));
+        }
+    }
+    out
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+// 内联样式，生成的 HTML 不依赖任何外部资源
+const HTML_STYLE: &str = "body{font-family:-apple-system,'Segoe UI',sans-serif;margin:24px;color:#222}\
+table{border-collapse:collapse;width:100%;margin-bottom:24px}\
+th,td{border:1px solid #ddd;padding:6px 10px;text-align:left;vertical-align:top;white-space:pre-wrap}\
+th{background:#f5f5f5}.PASS{color:#1a7f37}.WARN{color:#9a6700}.FAIL,.FAILED{color:#cf222e}";
+
+fn render_html(report: &Report) -> String {
+    let title = escape_html(&t("report.title"));
+    let mut out = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>{}</style>\n</head>\n<body>\n<h1>{}</h1>\n",
+        title, HTML_STYLE, title
+    );
+    for section in sections(report) {
+        out.push_str(&format!("<h2>{}</h2>\n", escape_html(&section.title)));
+        if section.rows.is_empty() {
+            out.push_str(&format!("<p>{}</p>\n", escape_html(&t("report.empty"))));
+            continue;
+        }
+        out.push_str("<table>\n<tr>");
+        for cell in &section.header {
+            out.push_str(&format!("<th>{}</th>", escape_html(cell)));
+        }
+        out.push_str("</tr>\n");
+        for row in &section.rows {
+            out.push_str("<tr>");
+            for cell in row {
+                // 状态列按结果着色
+                let class = match cell.as_str() {
+                    "PASS" | "WARN" | "FAIL" | "FAILED" => cell.as_str(),
+                    _ => "",
+                };
+                out.push_str(&format!(
+                    "<td class=\"{}\">{}</td>",
+                    class,
+                    escape_html(cell)
+                ));
+            }
+            out.push_str("</tr>\n");
+        }
+        out.push_str("</table>\n");
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+pub fn render(report: &Report, format: ReportFormat) -> Result<String, String> {
+    match format {
+        ReportFormat::Json => serde_json::to_string_pretty(report).map_err(|e| e.to_string()),
+        ReportFormat::Markdown => Ok(render_markdown(report)),
+        ReportFormat::Html => Ok(render_html(report)),
+    }
+}
+
+pub fn default_report_path(profile: &EditorProfile, format: ReportFormat) -> PathBuf {
+    let dir = get_app_data_dir().join("reports");
+    let _ = std::fs::create_dir_all(&dir);
+    dir.join(format!(
+        "report-{}-{}.{}",
+        profile.id,
+        file_stamp(now_secs()),
+        format.extension()
+    ))
+}
+
+// 生成报告并写入文件，返回文件路径
+pub fn export_report(
+    profile: &EditorProfile,
+    format: ReportFormat,
+    mask: MaskMode,
+    target: &Path,
+) -> Result<String, String> {
+    let content = render(&build_report(profile, mask), format)?;
+    if let Some(parent) = target.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    std::fs::write(target, content).map_err(|e| e.to_string())?;
+    Ok(target.to_string_lossy().to_string())
+}
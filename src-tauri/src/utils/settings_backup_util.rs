@@ -0,0 +1,267 @@
+use crate::model::editor_model::EditorProfile;
+use crate::model::extension_model::ExtensionManifest;
+use crate::model::settings_backup_model::{
+    SettingsBackupMode, SettingsItem, SettingsRestoreResult, SettingsSnapshot,
+};
+use crate::utils::backup_util::create_backup_dir;
+use crate::utils::config_util::{get_app_data_dir, load_config};
+use crate::utils::editor_util::get_data_dir;
+use crate::utils::extension_util::{
+    build_manifest, compare_with_manifest, install_extension, read_manifest,
+};
+use crate::utils::fs_util::{copy_path, create_temp_dir, remove_path};
+use crate::utils::git_util::{commit_all, ensure_repo, export_commit, git};
+use crate::utils::i18n_util::{t, t_args};
+use crate::utils::install_util::{find_install_info, get_cli_path};
+use crate::utils::time_util::{file_stamp, format_utc, now_secs};
+use std::path::{Path, PathBuf};
+
+// 文件夹模式下每个快照目录中的描述文件
+const SNAPSHOT_META_FILE: &str = "snapshot.json";
+// git 模式下仓库所在的子目录
+const GIT_REPO_DIR: &str = "git";
+
+// 快照根目录：<设置的目录或应用数据目录/settings-backups>/<编辑器>
+pub fn get_snapshot_root(profile: &EditorProfile) -> PathBuf {
+    let config = load_config();
+    let root = if config.settings_backup_dir.is_empty() {
+        get_app_data_dir().join("settings-backups")
+    } else {
+        PathBuf::from(&config.settings_backup_dir)
+    };
+    root.join(&profile.id)
+}
+
+// 编辑器的 User 目录（settings.json、keybindings.json、snippets 所在位置）
+fn get_user_dir(profile: &EditorProfile) -> Result<PathBuf, String> {
+    get_data_dir(profile)
+        .map(|dir| dir.join("User"))
+        .ok_or_else(|| t("error.data_dir_not_found"))
+}
+
+fn write_manifest(manifest: &ExtensionManifest, path: &Path) -> Result<(), String> {
+    let json_str = serde_json::to_string_pretty(manifest).map_err(|e| e.to_string())?;
+    std::fs::write(path, json_str).map_err(|e| e.to_string())
+}
+
+// 将当前配置复制到目标目录，返回实际复制的项
+fn collect_items(
+    profile: &EditorProfile,
+    items: &[SettingsItem],
+    target: &Path,
+) -> Result<Vec<SettingsItem>, String> {
+    let user_dir = get_user_dir(profile)?;
+    let mut collected = vec![];
+    for item in items {
+        let dst = target.join(item.file_name());
+        let copied = match item {
+            SettingsItem::Extensions => {
+                write_manifest(&build_manifest(profile), &dst)?;
+                true
+            }
+            _ => copy_path(&user_dir.join(item.file_name()), &dst)?,
+        };
+        if copied {
+            collected.push(*item);
+        }
+    }
+    Ok(collected)
+}
+
+// 创建配置快照，保存方式取决于应用配置
+pub fn create_snapshot(profile: &EditorProfile) -> Result<SettingsSnapshot, String> {
+    let root = get_snapshot_root(profile);
+    let now = now_secs();
+    match load_config().settings_backup_mode {
+        SettingsBackupMode::Folder => {
+            let id = file_stamp(now);
+            let dir = root.join(&id);
+            std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+            let items = collect_items(profile, &SettingsItem::ALL, &dir)?;
+            let snapshot = SettingsSnapshot {
+                id,
+                editor: profile.id.clone(),
+                created_at: format_utc(now),
+                mode: SettingsBackupMode::Folder,
+                items,
+                location: dir.to_string_lossy().to_string(),
+            };
+            let json_str = serde_json::to_string_pretty(&snapshot).map_err(|e| e.to_string())?;
+            std::fs::write(dir.join(SNAPSHOT_META_FILE), json_str).map_err(|e| e.to_string())?;
+            Ok(snapshot)
+        }
+        SettingsBackupMode::Git => {
+            let repo = root.join(GIT_REPO_DIR);
+            ensure_repo(&repo)?;
+            // 先清理上一次的内容，这样删除的片段文件也会体现在提交中
+            for item in SettingsItem::ALL {
+                remove_path(&repo.join(item.file_name())).map_err(|e| e.to_string())?;
+            }
+            let items = collect_items(profile, &SettingsItem::ALL, &repo)?;
+            let created_at = format_utc(now);
+            let id = commit_all(
+                &repo,
+                &format!("{} settings snapshot {}", profile.id, created_at),
+            )?;
+            Ok(SettingsSnapshot {
+                id,
+                editor: profile.id.clone(),
+                created_at,
+                mode: SettingsBackupMode::Git,
+                items,
+                location: repo.to_string_lossy().to_string(),
+            })
+        }
+    }
+}
+
+fn list_folder_snapshots(root: &Path) -> Vec<SettingsSnapshot> {
+    let mut snapshots = vec![];
+    if let Ok(entries) = std::fs::read_dir(root) {
+        for entry in entries.flatten() {
+            let meta_path = entry.path().join(SNAPSHOT_META_FILE);
+            if let Ok(contents) = std::fs::read_to_string(&meta_path) {
+                if let Ok(snapshot) = serde_json::from_str::<SettingsSnapshot>(&contents) {
+                    snapshots.push(snapshot);
+                }
+            }
+        }
+    }
+    snapshots
+}
+
+fn list_git_snapshots(
+    profile: &EditorProfile,
+    repo: &Path,
+) -> Result<Vec<SettingsSnapshot>, String> {
+    if !repo.join(".git").exists() {
+        return Ok(vec![]);
+    }
+    let log = git(repo, &["log", "--format=%H %ct"])?;
+    let mut snapshots = vec![];
+    for line in log.lines() {
+        let (id, time) = match line.split_once(' ') {
+            Some(parts) => parts,
+            None => continue,
+        };
+        let files = git(repo, &["ls-tree", "--name-only", id])?;
+        let items = SettingsItem::ALL
+            .into_iter()
+            .filter(|item| files.lines().any(|file| file == item.file_name()))
+            .collect();
+        snapshots.push(SettingsSnapshot {
+            id: id.to_string(),
+            editor: profile.id.clone(),
+            created_at: format_utc(time.parse().unwrap_or_default()),
+            mode: SettingsBackupMode::Git,
+            items,
+            location: repo.to_string_lossy().to_string(),
+        });
+    }
+    Ok(snapshots)
+}
+
+// 列出当前保存方式下的全部快照，最新的在前
+pub fn list_snapshots(profile: &EditorProfile) -> Result<Vec<SettingsSnapshot>, String> {
+    let root = get_snapshot_root(profile);
+    let mut snapshots = match load_config().settings_backup_mode {
+        SettingsBackupMode::Folder => list_folder_snapshots(&root),
+        SettingsBackupMode::Git => list_git_snapshots(profile, &root.join(GIT_REPO_DIR))?,
+    };
+    snapshots.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+    Ok(snapshots)
+}
+
+// 按清单安装缺失或版本不一致的扩展，返回 (成功, 失败)
+fn restore_extensions(
+    profile: &EditorProfile,
+    manifest_path: &Path,
+) -> Result<(Vec<String>, Vec<String>), String> {
+    let manifest = read_manifest(manifest_path)?;
+    let diff = compare_with_manifest(profile, &manifest);
+    let mut pending: Vec<(String, String)> = diff
+        .missing
+        .into_iter()
+        .map(|entry| (entry.id, entry.version))
+        .collect();
+    pending.extend(
+        diff.version_mismatch
+            .into_iter()
+            .map(|entry| (entry.id, entry.manifest_version)),
+    );
+    if pending.is_empty() {
+        return Ok((vec![], vec![]));
+    }
+
+    // 与 get_cursor_install_info 使用相同的安装位置查找逻辑
+    let install_info = find_install_info(profile);
+    let cli = get_cli_path(profile, &install_info).ok_or_else(|| t("error.cli_not_found"))?;
+    let mut installed = vec![];
+    let mut failed = vec![];
+    for (id, version) in pending {
+        let spec = format!("{}@{}", id, version);
+        if install_extension(&cli, &spec) {
+            installed.push(spec);
+        } else {
+            failed.push(spec);
+        }
+    }
+    Ok((installed, failed))
+}
+
+// 将快照中选中的项恢复到当前安装，恢复前先备份当前配置
+pub fn restore_snapshot(
+    profile: &EditorProfile,
+    id: &str,
+    items: &[SettingsItem],
+) -> Result<SettingsRestoreResult, String> {
+    let snapshot = list_snapshots(profile)?
+        .into_iter()
+        .find(|snapshot| snapshot.id == id)
+        .ok_or_else(|| t_args("error.settings_snapshot_not_found", &[id]))?;
+
+    // git 模式下先把对应提交导出到临时目录
+    let export_dir = create_temp_dir("settings")?;
+    let source = match snapshot.mode {
+        SettingsBackupMode::Folder => PathBuf::from(&snapshot.location),
+        SettingsBackupMode::Git => {
+            export_commit(
+                Path::new(&snapshot.location),
+                &snapshot.id,
+                &export_dir.path,
+            )?;
+            export_dir.path.clone()
+        }
+    };
+
+    let selected: Vec<SettingsItem> = items
+        .iter()
+        .copied()
+        .filter(|item| snapshot.items.contains(item))
+        .collect();
+    let backup_dir = create_backup_dir("settings-restore")?;
+    collect_items(profile, &selected, &backup_dir)?;
+
+    let user_dir = get_user_dir(profile)?;
+    let mut result = SettingsRestoreResult {
+        backup_path: backup_dir.to_string_lossy().to_string(),
+        ..Default::default()
+    };
+    for item in selected {
+        let src = source.join(item.file_name());
+        match item {
+            SettingsItem::Extensions => {
+                let (installed, failed) = restore_extensions(profile, &src)?;
+                result.installed_extensions = installed;
+                result.failed_extensions = failed;
+            }
+            _ => {
+                let dst = user_dir.join(item.file_name());
+                remove_path(&dst).map_err(|e| e.to_string())?;
+                copy_path(&src, &dst)?;
+            }
+        }
+        result.restored.push(item);
+    }
+    Ok(result)
+}
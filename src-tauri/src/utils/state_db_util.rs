@@ -0,0 +1,228 @@
+use crate::model::editor_model::EditorProfile;
+use crate::model::state_model::{StateItem, StateValue};
+use crate::utils::backup_util::{copy_into, create_backup_dir};
+use crate::utils::editor_util::get_data_dir;
+use crate::utils::fs_util::{create_temp_dir, TempDir};
+use crate::utils::i18n_util::t_args;
+use crate::utils::redact_util::{is_secret_key, redact_secret, redact_secrets};
+use rusqlite::types::ValueRef;
+use rusqlite::{Connection, OpenFlags, OptionalExtension};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+// VS Code 系编辑器的全局状态库：User/globalStorage/state.vscdb
+pub fn get_global_state_db_path(profile: &EditorProfile) -> Option<PathBuf> {
+    get_data_dir(profile)
+        .map(|dir| dir.join("User").join("globalStorage").join("state.vscdb"))
+        .filter(|path| path.exists())
+}
+
+// 临时副本，离开作用域时连同临时目录一起删除
+pub struct DbCopy {
+    dir: TempDir,
+    pub path: PathBuf,
+}
+
+// 将数据库（连同 -wal / -shm）复制到临时目录，避免读取时影响正在运行的编辑器
+pub fn copy_to_temp(path: &Path) -> Result<DbCopy, String> {
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| format!("invalid path: {}", path.display()))?
+        .to_string_lossy()
+        .to_string();
+    let dir = create_temp_dir("db")?;
+    let copy = DbCopy {
+        path: dir.path.join(&file_name),
+        dir,
+    };
+    std::fs::copy(path, &copy.path).map_err(|e| e.to_string())?;
+    for suffix in ["-wal", "-shm"] {
+        let sidecar = PathBuf::from(format!("{}{}", path.to_string_lossy(), suffix));
+        if sidecar.exists() {
+            let _ = std::fs::copy(
+                &sidecar,
+                copy.dir.path.join(format!("{}{}", file_name, suffix)),
+            );
+        }
+    }
+    Ok(copy)
+}
+
+// 以只读方式打开数据库副本
+pub fn open_read_only(copy: &DbCopy) -> Result<Connection, String> {
+    Connection::open_with_flags(&copy.path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+        .map_err(|e| e.to_string())
+}
+
+// ItemTable 的值可能是 TEXT 也可能是 BLOB，统一转为字符串
+pub fn value_to_string(value: ValueRef<'_>) -> String {
+    match value {
+        ValueRef::Text(bytes) | ValueRef::Blob(bytes) => String::from_utf8_lossy(bytes).to_string(),
+        ValueRef::Integer(i) => i.to_string(),
+        ValueRef::Real(f) => f.to_string(),
+        ValueRef::Null => "".to_string(),
+    }
+}
+
+// 从 ItemTable 中读取单个键的值（基于临时副本）
+pub fn read_item(db_path: &Path, key: &str) -> Result<Option<String>, String> {
+    let copy = copy_to_temp(db_path)?;
+    let conn = open_read_only(&copy)?;
+    conn.query_row("SELECT value FROM ItemTable WHERE key = ?1", [key], |row| {
+        Ok(value_to_string(row.get_ref(0)?))
+    })
+    .optional()
+    .map_err(|e| e.to_string())
+}
+
+// 将数据库（连同 -wal / -shm）复制到指定目录
+pub fn copy_db_into(db_path: &Path, dir: &Path) -> Result<(), String> {
+    std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    copy_into(db_path, dir)?;
+    for suffix in ["-wal", "-shm"] {
+        let sidecar = PathBuf::from(format!("{}{}", db_path.to_string_lossy(), suffix));
+        if sidecar.exists() {
+            copy_into(&sidecar, dir)?;
+        }
+    }
+    Ok(())
+}
+
+// 备份数据库（连同 -wal / -shm），返回备份目录
+pub fn backup_db(db_path: &Path, label: &str) -> Result<String, String> {
+    let dir = create_backup_dir(label)?;
+    copy_db_into(db_path, &dir)?;
+    Ok(dir.to_string_lossy().to_string())
+}
+
+// 写入 ItemTable 中的单个键，调用方需先备份并确保编辑器未运行
+pub fn write_item(db_path: &Path, key: &str, value: &str) -> Result<(), String> {
+    let conn = Connection::open(db_path).map_err(|e| e.to_string())?;
+    conn.execute(
+        "INSERT OR REPLACE INTO ItemTable (key, value) VALUES (?1, ?2)",
+        [key, value],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+// 判断值的内容类型
+fn content_type(value: ValueRef<'_>) -> &'static str {
+    let bytes = match value {
+        ValueRef::Text(bytes) | ValueRef::Blob(bytes) => bytes,
+        ValueRef::Null => return "null",
+        _ => return "number",
+    };
+    let text = match std::str::from_utf8(bytes) {
+        Ok(text) => text,
+        Err(_) => return "binary",
+    };
+    match serde_json::from_str::<Value>(text) {
+        Ok(Value::Object(_)) => "object",
+        Ok(Value::Array(_)) => "array",
+        Ok(Value::String(_)) => "string",
+        Ok(Value::Number(_)) => "number",
+        Ok(Value::Bool(_)) => "boolean",
+        Ok(Value::Null) => "null",
+        Err(_) => "text",
+    }
+}
+
+fn storage_type(value: ValueRef<'_>) -> &'static str {
+    match value {
+        ValueRef::Text(_) => "text",
+        ValueRef::Blob(_) => "blob",
+        ValueRef::Integer(_) => "integer",
+        ValueRef::Real(_) => "real",
+        ValueRef::Null => "null",
+    }
+}
+
+fn value_size(value: ValueRef<'_>) -> u64 {
+    match value {
+        ValueRef::Text(bytes) | ValueRef::Blob(bytes) => bytes.len() as u64,
+        ValueRef::Null => 0,
+        _ => 8,
+    }
+}
+
+// 列出 ItemTable 的全部键（基于临时副本，只读）
+pub fn list_items(db_path: &Path) -> Result<Vec<StateItem>, String> {
+    let copy = copy_to_temp(db_path)?;
+    let conn = open_read_only(&copy)?;
+    let mut stmt = conn
+        .prepare("SELECT key, value FROM ItemTable ORDER BY key")
+        .map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map([], |row| {
+            let key: String = row.get(0)?;
+            let value = row.get_ref(1)?;
+            Ok(StateItem {
+                secret: is_secret_key(&key),
+                key,
+                size: value_size(value),
+                storage_type: storage_type(value).to_string(),
+                value_type: content_type(value).to_string(),
+            })
+        })
+        .map_err(|e| e.to_string())?;
+    rows.collect::<Result<Vec<StateItem>, _>>()
+        .map_err(|e| e.to_string())
+}
+
+// 读取单个键并脱敏
+pub fn read_redacted_item(db_path: &Path, key: &str) -> Result<StateValue, String> {
+    let copy = copy_to_temp(db_path)?;
+    let conn = open_read_only(&copy)?;
+    conn.query_row("SELECT value FROM ItemTable WHERE key = ?1", [key], |row| {
+        let value = row.get_ref(0)?;
+        let value_type = content_type(value);
+        let text = if value_type == "binary" {
+            redact_secret(&value_to_string(value))
+        } else {
+            redact_secrets(key, &value_to_string(value))
+        };
+        Ok(StateValue {
+            key: key.to_string(),
+            size: value_size(value),
+            value_type: value_type.to_string(),
+            value: text,
+        })
+    })
+    .optional()
+    .map_err(|e| e.to_string())?
+    .ok_or_else(|| t_args("error.state_key_not_found", &[key]))
+}
+
+// 数据库占用的磁盘空间（主文件 + -wal）
+pub fn db_size(db_path: &Path) -> u64 {
+    let wal = PathBuf::from(format!("{}-wal", db_path.to_string_lossy()));
+    [db_path.to_path_buf(), wal]
+        .iter()
+        .filter_map(|path| std::fs::metadata(path).ok())
+        .map(|metadata| metadata.len())
+        .sum()
+}
+
+// 完整性检查，返回发现的问题，没有问题时为空
+pub fn integrity_check(conn: &Connection) -> Result<Vec<String>, String> {
+    let mut stmt = conn
+        .prepare("PRAGMA integrity_check")
+        .map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map([], |row| row.get::<_, String>(0))
+        .map_err(|e| e.to_string())?;
+    let messages = rows
+        .collect::<Result<Vec<String>, _>>()
+        .map_err(|e| e.to_string())?;
+    Ok(messages
+        .into_iter()
+        .filter(|message| message != "ok")
+        .collect())
+}
+
+// 压缩数据库并把 WAL 合并回主文件
+pub fn vacuum(conn: &Connection) -> Result<(), String> {
+    conn.execute_batch("VACUUM; PRAGMA wal_checkpoint(TRUNCATE);")
+        .map_err(|e| e.to_string())
+}
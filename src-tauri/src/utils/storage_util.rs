@@ -0,0 +1,148 @@
+use crate::model::cursor_model::{mask_value, MaskMode};
+use crate::model::editor_model::{EditorProfile, TelemetryKeys};
+use crate::model::storage_model::{StorageEntry, StorageMatch};
+use crate::utils::cursor_util::get_package_path;
+use crate::utils::i18n_util::{t, t_args};
+use crate::utils::jsonc_util::read_file;
+use serde_json::Value;
+use std::path::Path;
+
+// 搜索结果预览的最大字符数
+const PREVIEW_CHARS: usize = 120;
+
+fn value_type(value: &Value) -> &'static str {
+    match value {
+        Value::Object(_) => "object",
+        Value::Array(_) => "array",
+        Value::String(_) => "string",
+        Value::Number(_) => "number",
+        Value::Bool(_) => "boolean",
+        Value::Null => "null",
+    }
+}
+
+// JSON Pointer 中键名需要转义 ~ 和 /
+fn escape_pointer_key(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+// 读取 storage.json，机器码按指定方式遮盖
+fn read_storage(profile: &EditorProfile, mask: MaskMode) -> Result<Value, String> {
+    let package_path = get_package_path(profile);
+    if package_path.is_empty() {
+        return Err(t("error.storage_not_found"));
+    }
+    let mut json = read_file(Path::new(&package_path))
+        .map_err(|e| t_args("error.config_parse", &[&format!("{}: {}", package_path, e)]))?;
+    mask_telemetry(&mut json, &profile.telemetry_keys, mask);
+    Ok(json)
+}
+
+fn mask_telemetry(json: &mut Value, keys: &TelemetryKeys, mask: MaskMode) {
+    if let Some(obj) = json.as_object_mut() {
+        for key in [
+            &keys.mac_machine_id,
+            &keys.machine_id,
+            &keys.sqm_id,
+            &keys.dev_device_id,
+        ] {
+            if let Some(Value::String(value)) = obj.get_mut(key.as_str()) {
+                *value = mask_value(value, mask);
+            }
+        }
+    }
+}
+
+fn build_entry(path: String, key: String, value: &Value) -> StorageEntry {
+    let children = match value {
+        Value::Object(obj) => obj
+            .iter()
+            .map(|(k, v)| build_entry(format!("{}/{}", path, escape_pointer_key(k)), k.clone(), v))
+            .collect(),
+        Value::Array(arr) => arr
+            .iter()
+            .enumerate()
+            .map(|(i, v)| build_entry(format!("{}/{}", path, i), i.to_string(), v))
+            .collect(),
+        _ => vec![],
+    };
+    StorageEntry {
+        path,
+        key,
+        value_type: value_type(value).to_string(),
+        size: serde_json::to_string(value).map(|s| s.len()).unwrap_or(0),
+        children,
+    }
+}
+
+// 返回 storage.json 的完整结构（不含值本身）
+pub fn list_storage_keys(profile: &EditorProfile) -> Result<Vec<StorageEntry>, String> {
+    let json = read_storage(profile, MaskMode::Partial)?;
+    Ok(build_entry("".to_string(), "".to_string(), &json).children)
+}
+
+// 按 JSON Pointer 读取单个值，路径为空时返回整个文件
+pub fn get_storage_value(
+    profile: &EditorProfile,
+    path: &str,
+    mask: MaskMode,
+) -> Result<Value, String> {
+    let json = read_storage(profile, mask)?;
+    json.pointer(path)
+        .cloned()
+        .ok_or_else(|| t_args("error.storage_key_not_found", &[path]))
+}
+
+fn search_value(
+    path: &str,
+    key: &str,
+    value: &Value,
+    query: &str,
+    matches: &mut Vec<StorageMatch>,
+) {
+    let value_matches = match value {
+        Value::String(s) => s.to_lowercase().contains(query),
+        _ => false,
+    };
+    if !path.is_empty() && (key.to_lowercase().contains(query) || value_matches) {
+        let preview = match value {
+            Value::String(s) => s.chars().take(PREVIEW_CHARS).collect(),
+            _ => "".to_string(),
+        };
+        matches.push(StorageMatch {
+            path: path.to_string(),
+            value_type: value_type(value).to_string(),
+            preview,
+        });
+    }
+    match value {
+        Value::Object(obj) => {
+            for (k, v) in obj {
+                search_value(
+                    &format!("{}/{}", path, escape_pointer_key(k)),
+                    k,
+                    v,
+                    query,
+                    matches,
+                );
+            }
+        }
+        Value::Array(arr) => {
+            for (i, v) in arr.iter().enumerate() {
+                search_value(&format!("{}/{}", path, i), "", v, query, matches);
+            }
+        }
+        _ => {}
+    }
+}
+
+// 在键名和字符串值中按子串搜索（不区分大小写）
+pub fn search_storage(profile: &EditorProfile, query: &str) -> Result<Vec<StorageMatch>, String> {
+    let json = read_storage(profile, MaskMode::Partial)?;
+    let mut matches = vec![];
+    let query = query.trim().to_lowercase();
+    if !query.is_empty() {
+        search_value("", "", &json, &query, &mut matches);
+    }
+    Ok(matches)
+}
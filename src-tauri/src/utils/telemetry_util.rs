@@ -0,0 +1,98 @@
+use crate::model::editor_model::EditorProfile;
+use crate::model::telemetry_model::TelemetrySettings;
+use crate::utils::backup_util::backup_file;
+use crate::utils::editor_util::{get_data_dir, get_home_config_dir};
+use crate::utils::i18n_util::{t, t_args};
+use crate::utils::jsonc_util::{read_file, update_file};
+use serde_json::{Map, Value};
+use std::path::{Path, PathBuf};
+
+pub const TELEMETRY_LEVEL_KEY: &str = "telemetry.telemetryLevel";
+pub const CRASH_REPORTER_KEY: &str = "enable-crash-reporter";
+// telemetry.telemetryLevel 的合法取值
+pub const TELEMETRY_LEVELS: [&str; 4] = ["all", "error", "crash", "off"];
+
+// 用户 settings.json 路径
+pub fn get_settings_path(profile: &EditorProfile) -> Option<PathBuf> {
+    get_data_dir(profile).map(|dir| dir.join("User").join("settings.json"))
+}
+
+// argv.json 路径（启动参数配置）
+pub fn get_argv_path(profile: &EditorProfile) -> PathBuf {
+    get_home_config_dir(profile).join("argv.json")
+}
+
+// 读取 JSONC 对象文件（允许注释和尾逗号），文件不存在时返回空对象
+fn read_json_object(path: &Path) -> Result<Map<String, Value>, String> {
+    match read_file(path) {
+        Ok(Value::Object(obj)) => Ok(obj),
+        Ok(_) => Err(t_args("error.config_parse", &[&path.to_string_lossy()])),
+        Err(e) => Err(t_args(
+            "error.config_parse",
+            &[&format!("{}: {}", path.to_string_lossy(), e)],
+        )),
+    }
+}
+
+// 修改前先备份原文件，再只改动目标键，注释和格式保持不变
+fn update_json_key(path: &Path, key: &str, value: Value) -> Result<(), String> {
+    if path.exists() {
+        backup_file(&path.to_string_lossy(), "settings")?;
+    }
+    update_file(path, &[key], &value)
+}
+
+// 与隐私或遥测相关的设置项（telemetry.telemetryLevel 单独返回）
+fn is_privacy_key(key: &str) -> bool {
+    let key = key.to_lowercase();
+    key != TELEMETRY_LEVEL_KEY.to_lowercase()
+        && (key.contains("privacy") || key.contains("telemetry"))
+}
+
+pub fn read_telemetry_settings(profile: &EditorProfile) -> Result<TelemetrySettings, String> {
+    let mut settings = Map::new();
+    if let Some(settings_path) = get_settings_path(profile) {
+        settings = read_json_object(&settings_path)?;
+    }
+    let argv = read_json_object(&get_argv_path(profile))?;
+
+    let telemetry_level = settings
+        .get(TELEMETRY_LEVEL_KEY)
+        .and_then(|value| value.as_str())
+        .unwrap_or_default()
+        .to_string();
+    let privacy_settings = settings
+        .iter()
+        .filter(|(key, _)| is_privacy_key(key))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect();
+    Ok(TelemetrySettings {
+        telemetry_level,
+        enable_crash_reporter: argv
+            .get(CRASH_REPORTER_KEY)
+            .and_then(|value| value.as_bool()),
+        privacy_settings,
+    })
+}
+
+// 设置 telemetry.telemetryLevel
+pub fn set_telemetry_level(profile: &EditorProfile, level: &str) -> Result<(), String> {
+    if !TELEMETRY_LEVELS.contains(&level) {
+        return Err(t_args("error.telemetry_level", &[level]));
+    }
+    let settings_path = get_settings_path(profile).ok_or_else(|| t("error.data_dir_not_found"))?;
+    // 先确认现有文件可以解析，避免覆盖用户手写的错误配置
+    read_json_object(&settings_path)?;
+    update_json_key(
+        &settings_path,
+        TELEMETRY_LEVEL_KEY,
+        Value::String(level.to_string()),
+    )
+}
+
+// 设置 argv.json 中的 enable-crash-reporter
+pub fn set_crash_reporter(profile: &EditorProfile, enabled: bool) -> Result<(), String> {
+    let argv_path = get_argv_path(profile);
+    read_json_object(&argv_path)?;
+    update_json_key(&argv_path, CRASH_REPORTER_KEY, Value::Bool(enabled))
+}
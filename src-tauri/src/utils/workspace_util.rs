@@ -0,0 +1,106 @@
+use crate::model::editor_model::EditorProfile;
+use crate::model::workspace_model::{WorkspacePruneResult, WorkspaceStorageEntry};
+use crate::utils::backup_util::create_backup_dir;
+use crate::utils::editor_util::get_data_dir;
+use crate::utils::fs_util::{copy_dir_all, dir_size, file_uri_to_path};
+use crate::utils::i18n_util::{t, t_args};
+use crate::utils::time_util::{format_utc, modified_secs};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+// User/workspaceStorage 目录
+pub fn get_workspace_storage_dir(profile: &EditorProfile) -> Result<PathBuf, String> {
+    get_data_dir(profile)
+        .map(|dir| dir.join("User").join("workspaceStorage"))
+        .ok_or_else(|| t("error.data_dir_not_found"))
+}
+
+// 读取 workspace.json，返回 (类型, URI)
+fn read_workspace_uri(dir: &Path) -> (String, String) {
+    let json = std::fs::read_to_string(dir.join("workspace.json"))
+        .ok()
+        .and_then(|contents| serde_json::from_str::<Value>(&contents).ok());
+    if let Some(json) = json {
+        for kind in ["folder", "workspace"] {
+            if let Some(uri) = json.get(kind).and_then(|uri| uri.as_str()) {
+                return (kind.to_string(), uri.to_string());
+            }
+        }
+    }
+    ("unknown".to_string(), "".to_string())
+}
+
+fn read_entry(dir: &Path) -> WorkspaceStorageEntry {
+    let (kind, uri) = read_workspace_uri(dir);
+    let local_path = file_uri_to_path(&uri);
+    let stale = local_path.as_ref().is_some_and(|path| !path.exists());
+    WorkspaceStorageEntry {
+        id: dir
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default(),
+        path: dir.to_string_lossy().to_string(),
+        uri,
+        kind,
+        local_path: local_path
+            .map(|path| path.to_string_lossy().to_string())
+            .unwrap_or_default(),
+        size: dir_size(dir),
+        modified_at: format_utc(modified_secs(dir)),
+        stale,
+    }
+}
+
+// 列出全部工作区存储目录，最近修改的在前
+pub fn list_workspace_storage(
+    profile: &EditorProfile,
+) -> Result<Vec<WorkspaceStorageEntry>, String> {
+    let storage_dir = get_workspace_storage_dir(profile)?;
+    let mut entries = vec![];
+    if let Ok(dirs) = std::fs::read_dir(&storage_dir) {
+        for dir in dirs.flatten() {
+            let path = dir.path();
+            if path.is_dir() {
+                entries.push(read_entry(&path));
+            }
+        }
+    }
+    entries.sort_by(|a, b| b.modified_at.cmp(&a.modified_at));
+    Ok(entries)
+}
+
+// 删除选中的工作区存储目录，删除前整体备份，调用方需确保编辑器未运行
+pub fn prune_workspace_storage(
+    profile: &EditorProfile,
+    ids: &[String],
+) -> Result<WorkspacePruneResult, String> {
+    let storage_dir = get_workspace_storage_dir(profile)?;
+    let mut dirs = vec![];
+    for id in ids {
+        // 只接受 workspaceStorage 下的直接子目录名
+        let dir = storage_dir.join(id);
+        if id.is_empty() || id.contains(['/', '\\']) || id.contains("..") || !dir.is_dir() {
+            return Err(t_args("error.workspace_not_found", &[id]));
+        }
+        dirs.push(dir);
+    }
+
+    let backup_dir = create_backup_dir("workspace-storage")?;
+    for dir in &dirs {
+        if let Some(name) = dir.file_name() {
+            copy_dir_all(dir, &backup_dir.join(name)).map_err(|e| e.to_string())?;
+        }
+    }
+
+    let mut result = WorkspacePruneResult {
+        backup_path: backup_dir.to_string_lossy().to_string(),
+        ..Default::default()
+    };
+    for (id, dir) in ids.iter().zip(&dirs) {
+        let size = dir_size(dir);
+        std::fs::remove_dir_all(dir).map_err(|e| e.to_string())?;
+        result.freed += size;
+        result.removed.push(id.clone());
+    }
+    Ok(result)
+}